@@ -1,7 +1,8 @@
 #![forbid(unsafe_code)]
 #![allow(dead_code)]
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::sync::RwLock;
 use regex::Regex;
 use lazy_static::lazy_static;
 
@@ -314,13 +315,280 @@ lazy_static! {
     };
 }
 
+const DOUBLING_VERBS: [&str; 34] = [
+    "admit", "commit", "submit", "permit", "omit", "emit", "transmit", "remit", "acquit", "regret",
+    "prefer", "refer", "confer", "defer", "infer", "transfer", "deter", "occur", "recur", "incur",
+    "concur", "compel", "expel", "propel", "repel", "rebel", "control", "patrol", "equip",
+    "unwrap", "unzip", "unplug", "outfit", "embed",
+];
+
+const IRREGULAR_CONJUGATIONS: [(&str, &str, &str); 96] = [
+    ("be", "was", "been"),
+    ("bet", "bet", "bet"),
+    ("begin", "began", "begun"),
+    ("bind", "bound", "bound"),
+    ("break", "broke", "broken"),
+    ("bring", "brought", "brought"),
+    ("broadcast", "broadcast", "broadcast"),
+    ("build", "built", "built"),
+    ("buy", "bought", "bought"),
+    ("catch", "caught", "caught"),
+    ("choose", "chose", "chosen"),
+    ("come", "came", "come"),
+    ("cost", "cost", "cost"),
+    ("cut", "cut", "cut"),
+    ("dig", "dug", "dug"),
+    ("do", "did", "done"),
+    ("draw", "drew", "drawn"),
+    ("drink", "drank", "drunk"),
+    ("drive", "drove", "driven"),
+    ("eat", "ate", "eaten"),
+    ("fall", "fell", "fallen"),
+    ("feed", "fed", "fed"),
+    ("feel", "felt", "felt"),
+    ("fight", "fought", "fought"),
+    ("find", "found", "found"),
+    ("fly", "flew", "flown"),
+    ("forbid", "forbade", "forbidden"),
+    ("forget", "forgot", "forgotten"),
+    ("forgive", "forgave", "forgiven"),
+    ("freeze", "froze", "frozen"),
+    ("get", "got", "gotten"),
+    ("give", "gave", "given"),
+    ("go", "went", "gone"),
+    ("grow", "grew", "grown"),
+    ("have", "had", "had"),
+    ("hear", "heard", "heard"),
+    ("hide", "hid", "hidden"),
+    ("hit", "hit", "hit"),
+    ("hold", "held", "held"),
+    ("hurt", "hurt", "hurt"),
+    ("keep", "kept", "kept"),
+    ("know", "knew", "known"),
+    ("lay", "laid", "laid"),
+    ("lead", "led", "led"),
+    ("leave", "left", "left"),
+    ("lend", "lent", "lent"),
+    ("let", "let", "let"),
+    ("lie", "lay", "lain"),
+    ("lose", "lost", "lost"),
+    ("make", "made", "made"),
+    ("mean", "meant", "meant"),
+    ("meet", "met", "met"),
+    ("overwrite", "overwrote", "overwritten"),
+    ("pay", "paid", "paid"),
+    ("put", "put", "put"),
+    ("quit", "quit", "quit"),
+    ("read", "read", "read"),
+    ("rebuild", "rebuilt", "rebuilt"),
+    ("redo", "redid", "redone"),
+    ("rerun", "reran", "rerun"),
+    ("reset", "reset", "reset"),
+    ("rewrite", "rewrote", "rewritten"),
+    ("ride", "rode", "ridden"),
+    ("ring", "rang", "rung"),
+    ("rise", "rose", "risen"),
+    ("run", "ran", "run"),
+    ("say", "said", "said"),
+    ("see", "saw", "seen"),
+    ("seek", "sought", "sought"),
+    ("sell", "sold", "sold"),
+    ("send", "sent", "sent"),
+    ("set", "set", "set"),
+    ("shake", "shook", "shaken"),
+    ("shoot", "shot", "shot"),
+    ("show", "showed", "shown"),
+    ("shut", "shut", "shut"),
+    ("sing", "sang", "sung"),
+    ("sit", "sat", "sat"),
+    ("sleep", "slept", "slept"),
+    ("speak", "spoke", "spoken"),
+    ("spend", "spent", "spent"),
+    ("split", "split", "split"),
+    ("spread", "spread", "spread"),
+    ("stand", "stood", "stood"),
+    ("steal", "stole", "stolen"),
+    ("stick", "stuck", "stuck"),
+    ("take", "took", "taken"),
+    ("teach", "taught", "taught"),
+    ("tell", "told", "told"),
+    ("think", "thought", "thought"),
+    ("throw", "threw", "thrown"),
+    ("understand", "understood", "understood"),
+    ("undo", "undid", "undone"),
+    ("upset", "upset", "upset"),
+    ("win", "won", "won"),
+    ("write", "wrote", "written"),
+];
+
+type ConjugationRules = (Vec<(Regex, String)>, Vec<(Regex, String)>);
+lazy_static! {
+    static ref CONJUGATION_RULES: ConjugationRules = {
+        let suffixed_rules = |suffix: &str| -> Vec<(String, String)> {
+            let mut rules: Vec<(String, String)> = DOUBLING_VERBS
+                .iter()
+                .map(|verb| {
+                    (
+                        format!(r"(?i)^(?P<a>{})$", verb),
+                        format!("${{a}}{}{}", &verb[verb.len() - 1..], suffix),
+                    )
+                })
+                .collect();
+            rules.push((r"(?i)(?P<a>[aeiou])c$".to_string(), format!("${{a}}ck{}", suffix)));
+            rules.push((
+                r"(?i)^(?P<a>(?:qu|[^aeiouy])*[aeiou])(?P<b>[bdgklmnprtvz])$".to_string(),
+                format!("${{a}}${{b}}${{b}}{}", suffix),
+            ));
+            rules
+        };
+
+        let mut past = suffixed_rules("ed");
+        past.extend([
+            (r"(?i)(?P<a>[^aeiouy])y$".to_string(), "${a}ied".to_string()),
+            (r"(?i)e$".to_string(), "ed".to_string()),
+            (r"$".to_string(), "ed".to_string()),
+        ]);
+
+        let mut present_participle = vec![
+            (r"(?i)ie$".to_string(), "ying".to_string()),
+            (r"(?i)(?P<a>^b|[eoy])e$".to_string(), "${a}eing".to_string()),
+        ];
+        present_participle.extend(suffixed_rules("ing"));
+        present_participle.extend([
+            (r"(?i)e$".to_string(), "ing".to_string()),
+            (r"$".to_string(), "ing".to_string()),
+        ]);
+
+        let compile = |rules: Vec<(String, String)>| -> Vec<(Regex, String)> {
+            rules
+                .into_iter()
+                .map(|(rule, repl)| (Regex::new(&rule).unwrap(), repl))
+                .collect()
+        };
+        (compile(past), compile(present_participle))
+    };
+}
+
+const IRREGULAR_ADJECTIVES: [(&str, &str, &str); 9] = [
+    ("good", "better", "best"),
+    ("well", "better", "best"),
+    ("bad", "worse", "worst"),
+    ("ill", "worse", "worst"),
+    ("far", "farther", "farthest"),
+    ("little", "less", "least"),
+    ("many", "more", "most"),
+    ("much", "more", "most"),
+    ("fun", "more fun", "most fun"),
+];
+
+type AdjectiveRules = (Vec<(Regex, String)>, Vec<(Regex, String)>);
+lazy_static! {
+    static ref ADJECTIVE_RULES: AdjectiveRules = {
+        let rules = |suffix: &str| -> Vec<(Regex, String)> {
+            [
+                (r"(?i)(?P<a>[^aeiou])y$".to_string(), format!("${{a}}i{}", suffix)),
+                (r"(?i)e$".to_string(), suffix.to_string()),
+                (
+                    r"(?i)^(?P<a>(?:qu|[^aeiouy])*[aeiou])(?P<b>[bdgmnprt])$".to_string(),
+                    format!("${{a}}${{b}}${{b}}{}", suffix),
+                ),
+                (r"$".to_string(), suffix.to_string()),
+            ]
+            .into_iter()
+            .map(|(rule, repl)| (Regex::new(&rule).unwrap(), repl))
+            .collect()
+        };
+        (rules("er"), rules("est"))
+    };
+}
+
+lazy_static! {
+    static ref PRESERVED_CASINGS: RwLock<HashMap<String, String>> = RwLock::new(HashMap::new());
+    static ref HUMANS: RwLock<Vec<(Regex, String)>> = RwLock::new(Vec::new());
+    static ref ACRONYMS: RwLock<HashSet<String>> = RwLock::new(
+        [
+            "API", "ATM", "CEO", "CFO", "CLI", "CPU", "CSS", "CTO", "DNS", "DVD", "EULA", "FAQ",
+            "FBI", "GPU", "HTML", "HTTP", "ID", "IO", "IOU", "IP", "NASA", "OS", "PDF", "PhD",
+            "SDK", "SQL", "TCP", "UFO", "UI", "URI", "URL", "USB", "UUID", "VM", "XML",
+        ]
+        .iter()
+        .map(|acronym| acronym.to_string())
+        .collect()
+    );
+    static ref LETTER_PLURAL: RwLock<inflection::LetterPlural> =
+        RwLock::new(inflection::LetterPlural::Apostrophe);
+    static ref POSTPOSITIVES: RwLock<Vec<Regex>> = RwLock::new(Vec::new());
+    static ref DEFAULT_POSTPOSITIVES: Vec<Regex> = [
+        concat!(
+            r"(?i)^(?P<head>.+?)(?P<tail>[ -](?:in[ -]chief|in[ -]law|at[ -]arms|at[ -]law|",
+            r"of[ -]war|in[ -]waiting|martial|elect|designate|royal|public))$",
+        ),
+        concat!(
+            r"(?i)^(?P<head>(?:attorney|surgeon|secretary|governor|postmaster|solicitor|",
+            r"inspector|consul|auditor)s?)(?P<tail>[ -]general)$",
+        ),
+        concat!(
+            r"(?i)^(?P<head>(?:runner|hanger|passer|looker|goer|comer|whipper)s?)",
+            r"(?P<tail>[ -](?:up|on|by|in|out|off|about))$",
+        ),
+    ]
+    .iter()
+    .map(|pattern| Regex::new(pattern).unwrap())
+    .collect();
+    static ref ADJECTIVE_IRREGULARS: RwLock<Vec<(String, String, String)>> = RwLock::new(
+        IRREGULAR_ADJECTIVES
+            .iter()
+            .map(|(base, comparative, superlative)| {
+                (base.to_string(), comparative.to_string(), superlative.to_string())
+            })
+            .collect()
+    );
+    static ref ARTICLE_OVERRIDES: RwLock<HashMap<String, String>> = RwLock::new(HashMap::new());
+    static ref VERB_CONJUGATIONS: RwLock<Vec<(String, String, String)>> = RwLock::new(
+        IRREGULAR_CONJUGATIONS
+            .iter()
+            .map(|(base, past, participle)| {
+                (base.to_string(), past.to_string(), participle.to_string())
+            })
+            .collect()
+    );
+    static ref VERB_IRREGULARS: RwLock<Vec<(String, String)>> = RwLock::new(
+        [
+            ("is", "are"),
+            ("was", "were"),
+            ("has", "have"),
+            ("does", "do"),
+            ("isn't", "aren't"),
+            ("wasn't", "weren't"),
+            ("hasn't", "haven't"),
+            ("doesn't", "don't"),
+            ("focuses", "focus"),
+            ("quizzes", "quiz"),
+            ("gases", "gas"),
+            ("buses", "bus"),
+        ]
+        .iter()
+        .map(|(singular, plural)| (singular.to_string(), plural.to_string()))
+        .collect()
+    );
+    static ref NAME_PREFIXES: RwLock<HashSet<inflection::NamePrefix>> = RwLock::new(
+        HashSet::from([inflection::NamePrefix::O])
+    );
+}
+
 #[doc = include_str ! ("./../README.md")]
 pub mod inflection {
-    use std::collections::HashSet;
-    use regex::Regex;
+    use std::collections::{HashMap, HashSet};
+    use regex::{Captures, Regex};
     use lazy_static::lazy_static;
+    use sha2::{Digest, Sha256};
 
-    use crate::UPS;
+    use crate::{
+        ACRONYMS, ADJECTIVE_IRREGULARS, ADJECTIVE_RULES, ARTICLE_OVERRIDES, CONJUGATION_RULES,
+        DEFAULT_POSTPOSITIVES,
+        HUMANS, LETTER_PLURAL, NAME_PREFIXES, POSTPOSITIVES, PRESERVED_CASINGS, UPS,
+        VERB_CONJUGATIONS, VERB_IRREGULARS,
+    };
 
     #[inline]
     fn get_uncountable() -> &'static HashSet<String> {
@@ -347,7 +615,7 @@ pub mod inflection {
             pub fn $func_name(number: $param_type) -> String {
                 let n = $abs(number);
                 match n % 100 {
-                    11 | 12 | 13 => "th".to_string(),
+                    11..=13 => "th".to_string(),
                     _ => match n % 10 {
                         1 => "st".to_string(),
                         2 => "nd".to_string(),
@@ -396,95 +664,347 @@ pub mod inflection {
     }
 
     pub fn camelize_upper<S: AsRef<str>>(string: S, uppercase_first_letter: bool) -> String {
-        let input_string = string.as_ref().to_owned();
+        camelize_with_sep(string, uppercase_first_letter, "::".to_string())
+    }
 
-        if input_string.is_empty() {
-            return input_string;
+    pub fn camelize_with_locale<S: AsRef<str>>(
+        string: S,
+        uppercase_first_letter: bool,
+        locale: Locale,
+    ) -> String {
+        _camelize(string.as_ref(), uppercase_first_letter, "::", locale)
+    }
+
+    fn to_titlecase(c: char) -> String {
+        match c {
+            'Ǆ' | 'ǅ' | 'ǆ' => "ǅ".to_string(),
+            'Ǉ' | 'ǈ' | 'ǉ' => "ǈ".to_string(),
+            'Ǌ' | 'ǋ' | 'ǌ' => "ǋ".to_string(),
+            'Ǳ' | 'ǲ' | 'ǳ' => "ǲ".to_string(),
+            'ŉ' => "ʼN".to_string(),
+            // Greek letters with ypogegrammeni titlecase to the prosgegrammeni form
+            '\u{1F80}'..='\u{1F87}' | '\u{1F90}'..='\u{1F97}' | '\u{1FA0}'..='\u{1FA7}' => {
+                char::from_u32(c as u32 + 8).unwrap_or(c).to_string()
+            }
+            '\u{1F88}'..='\u{1F8F}' | '\u{1F98}'..='\u{1F9F}' | '\u{1FA8}'..='\u{1FAF}' => {
+                c.to_string()
+            }
+            'ᾳ' | 'ᾼ' => "ᾼ".to_string(),
+            'ῃ' | 'ῌ' => "ῌ".to_string(),
+            'ῳ' | 'ῼ' => "ῼ".to_string(),
+            _ => {
+                let mut upper = c.to_uppercase();
+                match upper.next() {
+                    Some(first) => std::iter::once(first)
+                        .chain(upper.flat_map(char::to_lowercase))
+                        .collect(),
+                    None => c.to_string(),
+                }
+            }
         }
+    }
 
-        if uppercase_first_letter {
-            lazy_static! {
-                static ref CU_RE: Regex = Regex::new(r"(?:^|_)(.)").unwrap();
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub enum Locale {
+        #[default]
+        Default,
+        Turkish,
+        Azeri,
+        Lithuanian,
+        German,
+    }
+
+    fn is_combining_above(c: char) -> bool {
+        matches!(c, '\u{0300}'..='\u{0314}' | '\u{033D}'..='\u{0344}' | '\u{0346}')
+    }
+
+    fn to_titlecase_with_locale(c: char, locale: Locale) -> String {
+        match (locale, c) {
+            (Locale::Turkish | Locale::Azeri, 'i') => "İ".to_string(),
+            (Locale::German, 'ß') => "ẞ".to_string(),
+            _ => to_titlecase(c),
+        }
+    }
+
+    pub fn to_lowercase_with_locale<S: AsRef<str>>(string: S, locale: Locale) -> String {
+        let string = string.as_ref();
+        if locale == Locale::Default || locale == Locale::German {
+            return string.to_lowercase();
+        }
+
+        let mut result = String::with_capacity(string.len());
+        let mut pending = String::new();
+        let mut chars = string.chars().peekable();
+        while let Some(c) = chars.next() {
+            let next = chars.peek().copied();
+            let special = match (locale, c) {
+                (Locale::Turkish | Locale::Azeri, 'İ') => Some("i"),
+                (Locale::Turkish | Locale::Azeri, 'I') if next == Some('\u{0307}') => {
+                    chars.next();
+                    Some("i")
+                }
+                (Locale::Turkish | Locale::Azeri, 'I') => Some("ı"),
+                (Locale::Lithuanian, 'I') if next.is_some_and(is_combining_above) => {
+                    Some("i\u{0307}")
+                }
+                (Locale::Lithuanian, 'J') if next.is_some_and(is_combining_above) => {
+                    Some("j\u{0307}")
+                }
+                (Locale::Lithuanian, 'Į') if next.is_some_and(is_combining_above) => {
+                    Some("į\u{0307}")
+                }
+                (Locale::Lithuanian, 'Ì') => Some("i\u{0307}\u{0300}"),
+                (Locale::Lithuanian, 'Í') => Some("i\u{0307}\u{0301}"),
+                (Locale::Lithuanian, 'Ĩ') => Some("i\u{0307}\u{0303}"),
+                _ => None,
+            };
+            match special {
+                Some(lower) => {
+                    result.push_str(&pending.to_lowercase());
+                    pending.clear();
+                    result.push_str(lower);
+                }
+                None => pending.push(c),
+            }
+        }
+        result.push_str(&pending.to_lowercase());
+        result
+    }
+
+    pub fn to_uppercase_with_locale<S: AsRef<str>>(string: S, locale: Locale) -> String {
+        let string = string.as_ref();
+        let mut result = String::with_capacity(string.len());
+        let mut previous: Option<char> = None;
+        for c in string.chars() {
+            match (locale, c) {
+                (Locale::Turkish | Locale::Azeri, 'i') => result.push('İ'),
+                (Locale::German, 'ß') => result.push('ẞ'),
+                // The dot above a soft-dotted letter is dropped once it is uppercased
+                (Locale::Lithuanian, '\u{0307}')
+                    if matches!(previous, Some('i' | 'j' | 'į' | 'I' | 'J' | 'Į')) => {}
+                _ => result.extend(c.to_uppercase()),
             }
-            let mut result: String = input_string.to_owned();
+            previous = Some(c);
+        }
+        result
+    }
 
-            for cap in CU_RE.find_iter(&input_string) {
-                let replace_with = &cap
-                    .as_str()
+    fn _camelize_segment(segment: &str, locale: Locale) -> String {
+        lazy_static! {
+            static ref CU_RE: Regex = Regex::new(r"(?:^|_)(.)").unwrap();
+        }
+        CU_RE
+            .replace_all(segment, |caps: &Captures| {
+                caps[1]
                     .chars()
-                    .last()
-                    .unwrap_or(' ')
-                    .to_uppercase()
-                    .to_string();
-                result.replace_range(cap.range(), replace_with);
+                    .map(|c| to_titlecase_with_locale(c, locale))
+                    .collect::<String>()
+            })
+            .to_string()
+    }
+
+    fn _camelize(
+        input_string: &str,
+        uppercase_first_letter: bool,
+        sep: &str,
+        locale: Locale,
+    ) -> String {
+        if input_string.is_empty() {
+            return input_string.to_string();
+        }
+
+        let camelized = input_string
+            .split('/')
+            .map(|segment| _camelize_segment(segment, locale))
+            .collect::<Vec<String>>()
+            .join(sep);
+
+        if uppercase_first_letter {
+            return camelized;
+        }
+
+        match camelized.chars().next() {
+            Some(first) => {
+                let mut result = to_lowercase_with_locale(first.to_string(), locale);
+                result.push_str(&camelized[first.len_utf8()..]);
+                result
             }
-            return result;
+            None => camelized,
         }
+    }
 
-        let input_string = camelize_upper(input_string, true);
-        let mut result = string
-            .as_ref()
-            .to_string()
-            .chars()
-            .next()
-            .unwrap_or(' ')
-            .to_lowercase()
-            .to_string();
-        result.push_str(&input_string[1..]);
-        result
+    pub fn camelize_with_sep<S: AsRef<str>>(
+        string: S,
+        uppercase_first_letter: bool,
+        sep: String,
+    ) -> String {
+        _camelize(string.as_ref(), uppercase_first_letter, &sep, Locale::Default)
+    }
+
+    pub fn demodulize<S: AsRef<str>>(path: S) -> String {
+        demodulize_with_sep(path, "::".to_string())
+    }
+
+    pub fn demodulize_with_sep<S: AsRef<str>>(path: S, sep: String) -> String {
+        let path = path.as_ref();
+        if sep.is_empty() {
+            return path.to_string();
+        }
+        match path.rfind(&sep) {
+            Some(index) => path[index + sep.len()..].to_string(),
+            None => path.to_string(),
+        }
+    }
+
+    pub fn deconstantize<S: AsRef<str>>(path: S) -> String {
+        deconstantize_with_sep(path, "::".to_string())
+    }
+
+    pub fn deconstantize_with_sep<S: AsRef<str>>(path: S, sep: String) -> String {
+        let path = path.as_ref();
+        if sep.is_empty() {
+            return String::new();
+        }
+        match path.rfind(&sep) {
+            Some(index) => path[..index].to_string(),
+            None => String::new(),
+        }
     }
 
     pub fn dasherize<S: AsRef<str>>(word: S) -> String {
         word.as_ref().to_string().replace('_', "-")
     }
 
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct HumanizeOptions {
+        pub capitalize: bool,
+        pub keep_id_suffix: bool,
+        pub locale: Locale,
+    }
+
+    impl Default for HumanizeOptions {
+        fn default() -> Self {
+            HumanizeOptions {
+                capitalize: true,
+                keep_id_suffix: false,
+                locale: Locale::Default,
+            }
+        }
+    }
+
+    pub fn add_human<S: AsRef<str>, T: AsRef<str>>(
+        rule: S,
+        replacement: T,
+    ) -> Result<(), regex::Error> {
+        let rule = Regex::new(rule.as_ref())?;
+        HUMANS
+            .write()
+            .unwrap()
+            .insert(0, (rule, replacement.as_ref().to_string()));
+        Ok(())
+    }
+
+    pub fn remove_human<S: AsRef<str>>(rule: S) {
+        let rule = rule.as_ref();
+        HUMANS
+            .write()
+            .unwrap()
+            .retain(|(existing, _)| existing.as_str() != rule);
+    }
+
+    pub fn clear_humans() {
+        HUMANS.write().unwrap().clear();
+    }
+
     pub fn humanize<S: AsRef<str>>(word: S) -> String {
+        humanize_with(word, &HumanizeOptions::default())
+    }
+
+    pub fn humanize_with<S: AsRef<str>>(word: S, options: &HumanizeOptions) -> String {
+        _humanize(word.as_ref(), options, true)
+    }
+
+    fn _humanize(word: &str, options: &HumanizeOptions, preserve_casing: bool) -> String {
         lazy_static! {
+            static ref H_LEADING_PROG: Regex = Regex::new(r"^_+").unwrap();
             static ref H_ID_PROG: Regex = Regex::new(r"_id$").unwrap();
-            static ref H_STEM_PROG: Regex = Regex::new(r"(?i)([a-z\d]*)").unwrap();
+            static ref H_STEM_PROG: Regex =
+                Regex::new(r"([\p{L}\p{M}\d]+(?:['’][\p{L}\p{M}\d]+)*)").unwrap();
             static ref H_WORD_PROG: Regex = Regex::new(r"^\w").unwrap();
         }
 
-        let mut result: String = H_ID_PROG.replace_all(word.as_ref(), "").to_string();
+        let mut result: String = word.to_string();
+        for (rule, repl) in HUMANS.read().unwrap().iter() {
+            if rule.is_match(&result) {
+                result = rule.replace(&result, repl.as_str()).to_string();
+                break;
+            }
+        }
+        result = H_LEADING_PROG.replace(&result, "").to_string();
+        if !options.keep_id_suffix {
+            result = H_ID_PROG.replace_all(&result, "").to_string();
+        }
         result = result.replace('_', " ");
 
         if result.is_empty() {
             return result;
         }
 
-        let updated_result = result.to_owned();
-        for cap in H_STEM_PROG.find_iter(&updated_result) {
-            let replace_with = cap.as_str().to_lowercase().to_string();
-            result.replace_range(cap.range(), &replace_with);
+        let mut first_word_preserved = false;
+        result = H_STEM_PROG
+            .replace_all(&result, |caps: &Captures| {
+                let preserved = if preserve_casing {
+                    preserved_casing(&caps[1])
+                } else {
+                    None
+                };
+                if caps.get(1).is_some_and(|m| m.start() == 0) {
+                    first_word_preserved = preserved.is_some();
+                }
+                preserved.unwrap_or_else(|| to_lowercase_with_locale(&caps[1], options.locale))
+            })
+            .to_string();
+
+        if !options.capitalize || first_word_preserved {
+            return result;
         }
 
         let updated_result = result.to_owned();
         for cap in H_WORD_PROG.find_iter(&updated_result) {
-            let mut replace_with = cap
-                .as_str()
-                .chars()
-                .next()
-                .unwrap_or(' ')
-                .to_uppercase()
-                .to_string();
-            let last_part = &cap.as_str()[1..];
-            replace_with.push_str(last_part);
+            let replace_with = _capitalize(cap.as_str(), options.locale);
             result.replace_range(cap.range(), &replace_with);
         }
         result
     }
 
     pub fn underscore<S: AsRef<str>>(string: S) -> String {
+        underscore_with_sep(string, "::".to_string())
+    }
+
+    pub fn underscore_with_sep<S: AsRef<str>>(string: S, sep: String) -> String {
+        _underscore(string.as_ref(), &sep, Locale::Default)
+    }
+
+    pub fn underscore_with_locale<S: AsRef<str>>(string: S, locale: Locale) -> String {
+        _underscore(string.as_ref(), "::", locale)
+    }
+
+    fn _underscore(string: &str, sep: &str, locale: Locale) -> String {
         lazy_static! {
-            static ref U_PROG1: Regex = Regex::new(r"(?P<a>[A-Z]+)(?P<b>[A-Z][a-z])").unwrap();
-            static ref U_PROG2: Regex = Regex::new(r"(?P<a>[a-z\d])(?P<b>[A-Z])").unwrap();
+            static ref U_PROG1: Regex =
+                Regex::new(r"(?P<a>\p{Lu}+)(?P<b>[\p{Lu}\p{Lt}][\p{Ll}\p{M}])").unwrap();
+            static ref U_PROG2: Regex =
+                Regex::new(r"(?P<a>[\p{Ll}\p{M}\d])(?P<b>[\p{Lu}\p{Lt}])").unwrap();
         }
         let stand_in = "$a-$b";
-        let mut word = string.as_ref().to_string();
+        let mut word = string.to_string();
+        if !sep.is_empty() {
+            word = word.replace(sep, "/");
+        }
         word = U_PROG1.replace_all(&word, stand_in).to_string();
         word = U_PROG2.replace_all(&word, stand_in).to_string();
         word = word.replace('-', "_");
-        word.to_lowercase()
+        to_lowercase_with_locale(word, locale)
     }
 
     pub fn transliterate<S: AsRef<str>>(string: S) -> String {
@@ -521,8 +1041,191 @@ pub mod inflection {
         parameterize_with_sep::<S>(string, "-".to_string())
     }
 
+    pub fn add_postpositive<S: AsRef<str>>(pattern: S) -> Result<(), regex::Error> {
+        let rule = Regex::new(&format!("(?i){}", pattern.as_ref()))?;
+        if !rule.capture_names().any(|name| name == Some("head")) {
+            return Err(regex::Error::Syntax(
+                "postpositive pattern must capture a `head` group".to_string(),
+            ));
+        }
+        POSTPOSITIVES.write().unwrap().insert(0, rule);
+        Ok(())
+    }
+
+    pub fn remove_postpositive<S: AsRef<str>>(pattern: S) {
+        let pattern = format!("(?i){}", pattern.as_ref());
+        POSTPOSITIVES
+            .write()
+            .unwrap()
+            .retain(|rule| rule.as_str() != pattern);
+    }
+
+    pub fn clear_postpositives() {
+        POSTPOSITIVES.write().unwrap().clear();
+    }
+
+    fn inflect_head_noun(word: &str, inflect: fn(&str) -> String) -> Option<String> {
+        let head_of = |rule: &Regex| rule.captures(word)?.name("head").map(|head| head.range());
+        let head = POSTPOSITIVES
+            .read()
+            .unwrap()
+            .iter()
+            .find_map(head_of)
+            .or_else(|| DEFAULT_POSTPOSITIVES.iter().find_map(head_of))?;
+        Some(format!(
+            "{}{}{}",
+            &word[..head.start],
+            inflect(&word[head.clone()]),
+            &word[head.end..]
+        ))
+    }
+
+    fn titlecase_words(string: &str) -> String {
+        let mut previous_alphabetic = false;
+        string
+            .chars()
+            .map(|c| {
+                let titled = if previous_alphabetic {
+                    c.to_string()
+                } else {
+                    c.to_uppercase().to_string()
+                };
+                previous_alphabetic = c.is_alphanumeric() || c == '\'' || c == '’';
+                titled
+            })
+            .collect()
+    }
+
+    fn preserve_case(
+        word: &str,
+        symbol: fn(&str) -> Option<String>,
+        inflect: fn(&str) -> String,
+    ) -> String {
+        // Acronyms, numerals and letters keep their own casing, even as the last hump
+        if let Some(inflected) = symbol(word) {
+            return inflected;
+        }
+        if !word.chars().any(char::is_uppercase) {
+            return inflect(word);
+        }
+        if !word.chars().any(char::is_lowercase) {
+            let lower = word.to_lowercase();
+            let inflected = inflect(&lower);
+            // A suffix after a trailing digit isn't part of the capitalised word
+            if word.ends_with(|c: char| c.is_ascii_digit())
+                && let Some(suffix) = inflected.strip_prefix(lower.as_str())
+            {
+                return format!("{}{}", word, suffix);
+            }
+            return inflected.to_uppercase();
+        }
+
+        let is_title = |part: &str| {
+            let mut chars = part.chars();
+            chars.next().is_none_or(|first| !first.is_lowercase())
+                && !chars.any(char::is_uppercase)
+        };
+        if word.split([' ', '-', '_']).all(is_title) {
+            return titlecase_words(&inflect(&word.to_lowercase()));
+        }
+
+        // Only the last hump of a camel-cased word is inflected
+        let hump = word
+            .char_indices()
+            .zip(word.chars().skip(1))
+            .filter(|((_, previous), next)| previous.is_lowercase() && next.is_uppercase())
+            .map(|((index, previous), _)| index + previous.len_utf8())
+            .last();
+        match hump {
+            Some(index) => {
+                let hump = preserve_case(&word[index..], symbol, inflect);
+                format!("{}{}", &word[..index], hump)
+            }
+            None => inflect(word),
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum LetterPlural {
+        Apostrophe,
+        Plain,
+    }
+
+    pub fn set_letter_plural(style: LetterPlural) {
+        *LETTER_PLURAL.write().unwrap() = style;
+    }
+
+    pub fn add_acronym<S: AsRef<str>>(acronym: S) {
+        ACRONYMS.write().unwrap().insert(acronym.as_ref().to_string());
+    }
+
+    pub fn remove_acronym<S: AsRef<str>>(acronym: S) {
+        ACRONYMS.write().unwrap().remove(acronym.as_ref());
+    }
+
+    fn is_acronym(word: &str) -> bool {
+        ACRONYMS.read().unwrap().contains(word)
+    }
+
+    fn is_numeral(word: &str) -> bool {
+        let digits = word.strip_prefix(['\'', '’']).unwrap_or(word);
+        !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())
+    }
+
+    fn is_letter(word: &str) -> bool {
+        let mut chars = word.chars();
+        chars.next().is_some_and(|c| c.is_ascii_alphabetic()) && chars.next().is_none()
+    }
+
+    fn pluralize_symbol(word: &str) -> Option<String> {
+        if is_numeral(word) {
+            return Some(format!("{}s", word));
+        }
+        if is_letter(word) {
+            return Some(match *LETTER_PLURAL.read().unwrap() {
+                LetterPlural::Apostrophe => format!("{}'s", word),
+                LetterPlural::Plain => format!("{}s", word),
+            });
+        }
+        if is_acronym(word) {
+            let suffix = if word.ends_with('S') { "es" } else { "s" };
+            return Some(format!("{}{}", word, suffix));
+        }
+        let already_plural = word
+            .strip_suffix("'s")
+            .is_some_and(is_letter)
+            || word
+                .strip_suffix('s')
+                .is_some_and(|stem| is_numeral(stem) || is_acronym(stem));
+        already_plural.then(|| word.to_string())
+    }
+
+    fn singularize_symbol(word: &str) -> Option<String> {
+        if let Some(letter) = word.strip_suffix("'s").filter(|stem| is_letter(stem)) {
+            return Some(letter.to_string());
+        }
+        let plain_letter = *LETTER_PLURAL.read().unwrap() == LetterPlural::Plain;
+        if let Some(stem) = word.strip_suffix("es").filter(|stem| stem.ends_with('S'))
+            && is_acronym(stem)
+        {
+            return Some(stem.to_string());
+        }
+        if let Some(stem) = word.strip_suffix('s') {
+            let symbol = is_numeral(stem)
+                || is_acronym(stem)
+                || (plain_letter && is_letter(stem) && stem.chars().all(|c| c.is_uppercase()));
+            if symbol {
+                return Some(stem.to_string());
+            }
+        }
+        (is_numeral(word) || is_letter(word) || is_acronym(word)).then(|| word.to_string())
+    }
+
     pub fn pluralize<S: AsRef<str>>(string: S) -> String {
-        let word: &str = string.as_ref();
+        preserve_case(string.as_ref(), pluralize_symbol, _pluralize)
+    }
+
+    fn _pluralize(word: &str) -> String {
         let word_is_empty = word.is_empty();
         let word_is_in_uncountable: bool =
             get_uncountable().contains(word.to_lowercase().as_str());
@@ -531,6 +1234,10 @@ pub mod inflection {
             return word.to_string();
         }
 
+        if let Some(plural) = inflect_head_noun(word, |head| pluralize(head)) {
+            return plural;
+        }
+
         for (rule, repl) in get_plurals().iter() {
             // let re = Regex::new(rule).unwrap();
             if rule.is_match(word) {
@@ -542,7 +1249,10 @@ pub mod inflection {
     }
 
     pub fn singularize<S: AsRef<str>>(string: S) -> String {
-        let word = string.as_ref();
+        preserve_case(string.as_ref(), singularize_symbol, _singularize)
+    }
+
+    fn _singularize(word: &str) -> String {
 
         for re in get_uncountable_compiled().iter() {
             // let pattern = &format!(r"(?i)\b({})\z", inf);
@@ -552,6 +1262,10 @@ pub mod inflection {
             }
         }
 
+        if let Some(singular) = inflect_head_noun(word, |head| singularize(head)) {
+            return singular;
+        }
+
         for (rule, repl) in get_singulars().iter() {
             // let re = Regex::new(rule).unwrap();
             if rule.is_match(word) {
@@ -567,77 +1281,1759 @@ pub mod inflection {
         pluralize(underscore)
     }
 
-    fn capitalize<S: AsRef<str>>(s: S) -> String {
-        let mut c = s.as_ref().chars();
-        match c.next() {
-            None => String::new(),
-            Some(f) => f.to_uppercase().chain(c).collect(),
-        }
+    pub fn classify<S: AsRef<str>>(table_name: S) -> String {
+        let table_name = table_name.as_ref();
+        let table_name = match table_name.rfind('.') {
+            Some(index) => &table_name[index + 1..],
+            None => table_name,
+        };
+        camelize(singularize(table_name))
     }
 
-    pub fn titleize<S: AsRef<str>>(string: S) -> String {
-        let input_string = string.as_ref();
-        let mut result: String = underscore(&string);
-        result = humanize(result);
-        lazy_static! {
-            static ref H_FIRST_PROG: Regex = Regex::new(r"\b((\s+)?'?\w)").unwrap();
+    pub fn foreign_key<S: AsRef<str>>(class_name: S) -> String {
+        foreign_key_with_sep(class_name, "_".to_string())
+    }
+
+    pub fn foreign_key_with_sep<S: AsRef<str>>(class_name: S, sep: String) -> String {
+        format!("{}{}id", underscore(demodulize(class_name)), sep)
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct ModelName {
+        pub name: String,
+        pub singular: String,
+        pub plural: String,
+        pub element: String,
+        pub collection: String,
+        pub route_key: String,
+        pub singular_route_key: String,
+        pub param_key: String,
+        pub i18n_key: String,
+        pub table_name: String,
+        pub human: String,
+    }
+
+    impl ModelName {
+        pub fn new<S: AsRef<str>>(name: S) -> Self {
+            ModelName::_build(name.as_ref(), None)
         }
-        for cap in H_FIRST_PROG.find_iter(input_string) {
-            result.replace_range(cap.range(), cap.as_str());
+
+        pub fn with_namespace<S: AsRef<str>, N: AsRef<str>>(name: S, namespace: N) -> Self {
+            ModelName::_build(name.as_ref(), Some(namespace.as_ref()))
         }
-        result = result
-            .split(char::is_whitespace)
-            .map(|word| format!(" {}", capitalize(word)))
-            .collect::<String>()
-            .trim()
-            .to_string();
-        result
-    }
 
-    pub fn normalize_spaces<S: AsRef<str>>(string: S) -> String {
-        lazy_static! {
-            static ref NS_RE: Regex = Regex::new(r"\s+").unwrap();
+        fn _build(name: &str, namespace: Option<&str>) -> Self {
+            let singular = underscore(name).replace('/', "_");
+            let plural = pluralize(&singular);
+            let element = underscore(demodulize(name));
+
+            let param_key = match namespace {
+                Some(namespace) => {
+                    let prefix = format!("{}::", namespace);
+                    let unnamespaced = name.strip_prefix(&prefix).unwrap_or(name);
+                    underscore(unnamespaced).replace('/', "_")
+                }
+                None => singular.to_owned(),
+            };
+
+            let mut route_key = match namespace {
+                Some(_) => pluralize(&param_key),
+                None => plural.to_owned(),
+            };
+            let singular_route_key = singularize(&route_key);
+            if plural == singular {
+                route_key.push_str("_index");
+            }
+
+            ModelName {
+                name: name.to_string(),
+                human: humanize(&element),
+                collection: tableize(name),
+                i18n_key: underscore(name),
+                table_name: tableize(demodulize(name)),
+                singular,
+                plural,
+                element,
+                route_key,
+                singular_route_key,
+                param_key,
+            }
         }
-        let text = string.as_ref();
-        return NS_RE.replace_all(text, " ").trim().to_string();
     }
 
-    fn _only_alpha<S: AsRef<str>>(
-        string: S,
-        check_fn: fn(c: &char) -> bool,
-        repl: Option<char>,
-    ) -> String {
-        let chars = string.as_ref().chars();
-
-        chars
-            .filter_map(|c| if !check_fn(&c) { repl } else { Some(c) })
-            .collect()
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum NamePrefix {
+        Mc,
+        Mac,
+        O,
     }
 
-    pub fn only_alpha<S: AsRef<str>>(string: S, repl: Option<char>) -> String {
-        let check_fn = |c: &char| c.is_alphabetic();
-        _only_alpha(string.as_ref(), check_fn, repl)
+    const NAME_PREFIX_EXCEPTIONS: [&str; 4] = ["o'clock", "o’clock", "o'er", "o’er"];
+
+    pub fn add_preserved_casing<S: AsRef<str>>(word: S) {
+        let word = word.as_ref();
+        PRESERVED_CASINGS
+            .write()
+            .unwrap()
+            .insert(word.to_lowercase(), word.to_string());
     }
 
-    pub fn only_alphanum<S: AsRef<str>>(string: S, repl: Option<char>) -> String {
-        let check_fn = |c: &char| c.is_alphanumeric();
-        _only_alpha(string.as_ref(), check_fn, repl)
+    pub fn remove_preserved_casing<S: AsRef<str>>(word: S) {
+        PRESERVED_CASINGS
+            .write()
+            .unwrap()
+            .remove(&word.as_ref().to_lowercase());
     }
 
-    pub fn only_alpha_ascii<S: AsRef<str>>(string: S, repl: Option<char>) -> String {
-        let check_fn = |c: &char| c.is_ascii_alphabetic();
-        _only_alpha(string.as_ref(), check_fn, repl)
+    pub fn clear_preserved_casings() {
+        PRESERVED_CASINGS.write().unwrap().clear();
     }
 
-    pub fn only_alphanum_ascii<S: AsRef<str>>(string: S, repl: Option<char>) -> String {
-        let check_fn = |c: &char| c.is_ascii_alphanumeric();
-        _only_alpha(string.as_ref(), check_fn, repl)
+    pub fn set_name_prefix_casing(prefix: NamePrefix, enabled: bool) {
+        let mut prefixes = NAME_PREFIXES.write().unwrap();
+        if enabled {
+            prefixes.insert(prefix);
+        } else {
+            prefixes.remove(&prefix);
+        }
+    }
+
+    fn name_prefix_casing(word: &str) -> Option<String> {
+        if NAME_PREFIX_EXCEPTIONS.contains(&word) {
+            return None;
+        }
+        let prefixes = NAME_PREFIXES.read().unwrap();
+        let patterns = [
+            (NamePrefix::Mc, "mc"),
+            (NamePrefix::Mac, "mac"),
+            (NamePrefix::O, "o'"),
+            (NamePrefix::O, "o’"),
+        ];
+        for (prefix, pattern) in patterns {
+            if !prefixes.contains(&prefix) {
+                continue;
+            }
+            if let Some(rest) = word.strip_prefix(pattern)
+                && rest.chars().count() >= 2
+                && rest.chars().all(char::is_alphabetic)
+            {
+                return Some(format!(
+                    "{}{}",
+                    _capitalize(pattern, Locale::Default),
+                    _capitalize(rest, Locale::Default)
+                ));
+            }
+        }
+        None
+    }
+
+    fn preserved_casing(word: &str) -> Option<String> {
+        let start = word.find(char::is_alphanumeric)?;
+        let end = word
+            .char_indices()
+            .rev()
+            .find(|(_, c)| c.is_alphanumeric())
+            .map(|(i, c)| i + c.len_utf8())?;
+        let core = word[start..end].to_lowercase();
+
+        let casings = PRESERVED_CASINGS.read().unwrap();
+        let canonical = casings
+            .get(&core)
+            .cloned()
+            .or_else(|| {
+                ["'s", "’s"].iter().find_map(|suffix| {
+                    let base = core.strip_suffix(suffix)?;
+                    casings.get(base).map(|c| format!("{}{}", c, suffix))
+                })
+            })
+            .or_else(|| name_prefix_casing(&core))?;
+
+        Some(format!("{}{}{}", &word[..start], canonical, &word[end..]))
+    }
+
+    fn protect_preserved_words(string: &str) -> String {
+        lazy_static! {
+            static ref PPW_PROG: Regex =
+                Regex::new(r"[\p{L}\p{M}\d]+(?:['’][\p{L}\p{M}\d]+)*").unwrap();
+        }
+        PPW_PROG
+            .replace_all(string, |caps: &Captures| {
+                let word = &caps[0];
+                match preserved_casing(word) {
+                    Some(_) => word.to_lowercase(),
+                    None => word.to_string(),
+                }
+            })
+            .to_string()
+    }
+
+    pub fn capitalize<S: AsRef<str>>(s: S) -> String {
+        capitalize_with_locale(s, Locale::Default)
+    }
+
+    pub fn capitalize_with_locale<S: AsRef<str>>(s: S, locale: Locale) -> String {
+        let s = s.as_ref();
+        preserved_casing(s).unwrap_or_else(|| _capitalize(s, locale))
+    }
+
+    fn _capitalize(s: &str, locale: Locale) -> String {
+        let mut c = s.chars().peekable();
+        match c.next() {
+            None => String::new(),
+            Some(f) => {
+                let mut result = to_titlecase_with_locale(f, locale);
+                if locale == Locale::Lithuanian
+                    && matches!(f, 'i' | 'j' | 'į')
+                    && c.peek() == Some(&'\u{0307}')
+                {
+                    c.next();
+                }
+                result.extend(c);
+                result
+            }
+        }
+    }
+
+    pub fn titleize<S: AsRef<str>>(string: S) -> String {
+        titleize_with_locale(string, Locale::Default)
+    }
+
+    pub fn titleize_with_locale<S: AsRef<str>>(string: S, locale: Locale) -> String {
+        let options = HumanizeOptions {
+            locale,
+            ..Default::default()
+        };
+        _titleize(string.as_ref(), &options, true)
+    }
+
+    pub fn titleize_with<S: AsRef<str>>(string: S, options: &HumanizeOptions) -> String {
+        _titleize(string.as_ref(), options, true)
+    }
+
+    fn _titleize(input_string: &str, options: &HumanizeOptions, preserve_casing: bool) -> String {
+        let locale = options.locale;
+        let protected;
+        let input_string = if preserve_casing {
+            protected = protect_preserved_words(input_string);
+            protected.as_str()
+        } else {
+            input_string
+        };
+        let mut result: String = underscore_with_locale(input_string, locale);
+        result = _humanize(
+            &result,
+            &HumanizeOptions {
+                capitalize: true,
+                ..options.clone()
+            },
+            preserve_casing,
+        );
+        lazy_static! {
+            static ref H_FIRST_PROG: Regex = Regex::new(r"\b((\s+)?'?\w)").unwrap();
+        }
+        for cap in H_FIRST_PROG.find_iter(input_string) {
+            // Only restore the original text where it still lines up with the result
+            if let Some(current) = result.get(cap.range())
+                && current.to_lowercase() == cap.as_str().to_lowercase()
+            {
+                result.replace_range(cap.range(), cap.as_str());
+            }
+        }
+        result = result
+            .split(char::is_whitespace)
+            .map(|word| {
+                if preserve_casing {
+                    format!(" {}", capitalize_with_locale(word, locale))
+                } else {
+                    format!(" {}", _capitalize(word, locale))
+                }
+            })
+            .collect::<String>()
+            .trim()
+            .to_string();
+        result
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum TitleStyle {
+        Ap,
+        Apa,
+        Chicago,
+        Mla,
+    }
+
+    const TITLE_ARTICLES: [&str; 3] = ["a", "an", "the"];
+
+    const TITLE_CONJUNCTIONS: [&str; 7] = ["and", "but", "for", "nor", "or", "so", "yet"];
+
+    const TITLE_PREPOSITIONS: [&str; 57] = [
+        "about", "above", "across", "after", "against", "along", "among", "around", "as", "at",
+        "before", "behind", "below", "beneath", "beside", "between", "beyond", "by", "down",
+        "during", "except", "for", "from", "in", "inside", "into", "like", "near", "of", "off",
+        "on", "onto", "out", "outside", "over", "past", "per", "since", "than", "through",
+        "throughout", "till", "to", "toward", "towards", "under", "underneath", "until", "unto",
+        "up", "upon", "versus", "via", "vs", "with", "within", "without",
+    ];
+
+    fn is_minor_title_word(word: &str, style: TitleStyle) -> bool {
+        let is_article = TITLE_ARTICLES.contains(&word);
+        let is_conjunction = TITLE_CONJUNCTIONS.contains(&word);
+        let is_preposition = TITLE_PREPOSITIONS.contains(&word);
+        match style {
+            TitleStyle::Ap | TitleStyle::Apa => {
+                is_article || ((is_conjunction || is_preposition) && word.chars().count() <= 3)
+            }
+            TitleStyle::Chicago => {
+                is_article
+                    || is_preposition
+                    || matches!(word, "and" | "but" | "for" | "nor" | "or")
+            }
+            TitleStyle::Mla => is_article || is_conjunction || is_preposition,
+        }
+    }
+
+    fn capitalize_title_word(word: &str) -> String {
+        match word.find(char::is_alphanumeric) {
+            Some(index) => format!("{}{}", &word[..index], capitalize(&word[index..])),
+            None => word.to_string(),
+        }
+    }
+
+    fn ends_title_phrase(word: &str) -> bool {
+        word.ends_with([':', '?', '!', '.', '—'])
+    }
+
+    pub fn titleize_with_style<S: AsRef<str>>(string: S, style: TitleStyle) -> String {
+        let input_string = string.as_ref();
+
+        // Identifiers such as "TheManWithoutAPast" are split like `titleize` does,
+        // while hyphens are kept so compounds can follow the style guide.
+        let input_string = protect_preserved_words(input_string);
+        let text = if input_string.contains(char::is_whitespace) {
+            input_string.to_string()
+        } else {
+            input_string
+                .split('-')
+                .map(underscore)
+                .collect::<Vec<String>>()
+                .join("-")
+        };
+        let text = text.replace('_', " ");
+
+        let words: Vec<&str> = text.split_whitespace().collect();
+        let last_index = words.len().saturating_sub(1);
+
+        words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| {
+                let starts_phrase = i == 0 || ends_title_phrase(words[i - 1]);
+                let ends_phrase = i == last_index || ends_title_phrase(word);
+                let parts: Vec<&str> = word.split('-').collect();
+                let last_part = parts.len() - 1;
+
+                parts
+                    .iter()
+                    .enumerate()
+                    .map(|(j, part)| {
+                        let lower = part.to_lowercase();
+                        let core = lower.trim_matches(|c: char| !c.is_alphanumeric());
+                        let forced = (j == 0 && starts_phrase) || (j == last_part && ends_phrase);
+                        if is_acronym(part.trim_matches(|c: char| !c.is_alphanumeric())) {
+                            part.to_string()
+                        } else if !forced && is_minor_title_word(core, style) {
+                            lower
+                        } else {
+                            capitalize_title_word(&lower)
+                        }
+                    })
+                    .collect::<Vec<String>>()
+                    .join("-")
+            })
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+
+    fn is_mixed_case_word(word: &str) -> bool {
+        let mut chars = word.chars();
+        chars.next();
+        chars.any(char::is_uppercase)
+    }
+
+    const NAME_TITLES: [&str; 5] = ["mr", "mrs", "ms", "dr", "prof"];
+
+    const SENTENCE_ABBREVIATIONS: [&str; 16] = [
+        "mr", "mrs", "ms", "dr", "prof", "sr", "jr", "st", "vs", "inc", "ltd", "co", "corp",
+        "fig", "approx", "dept",
+    ];
+
+    fn ends_sentence(gap: &str, previous_word: &str) -> bool {
+        if gap.contains(['!', '?']) {
+            return true;
+        }
+        // "U.S. economy" and "Dr. Smith" carry on the sentence
+        let period = gap
+            .find('.')
+            .is_some_and(|index| gap[index + 1..].starts_with(char::is_whitespace));
+        period && !SENTENCE_ABBREVIATIONS.contains(&previous_word.to_lowercase().as_str())
+    }
+
+    pub fn sentence_case<S: AsRef<str>>(string: S) -> String {
+        lazy_static! {
+            static ref SC_WORD_PROG: Regex =
+                Regex::new(r"[\p{L}\p{M}\d]+(?:['’][\p{L}\p{M}\d]+)*").unwrap();
+        }
+        lazy_static! {
+            static ref SC_INITIALISM_PROG: Regex = Regex::new(r"\b(?:\p{Lu}\.){2,}").unwrap();
+        }
+        let text = string.as_ref();
+        // Mixed-case words are only kept when the whole text isn't shouting
+        let is_all_caps = !text.chars().any(char::is_lowercase);
+        let initialisms: Vec<(usize, usize)> = SC_INITIALISM_PROG
+            .find_iter(text)
+            .map(|found| (found.start(), found.end()))
+            .collect();
+
+        let mut result = String::with_capacity(text.len());
+        let mut last_end = 0;
+        let mut previous_word = "";
+        let mut previous_in_initialism = false;
+        let mut sentence_start = true;
+        for cap in SC_WORD_PROG.find_iter(text) {
+            let gap = &text[last_end..cap.start()];
+            if !previous_in_initialism && ends_sentence(gap, previous_word) {
+                sentence_start = true;
+            }
+            result.push_str(gap);
+
+            let word = cap.as_str();
+            let lower = word.to_lowercase();
+            let in_initialism = initialisms
+                .iter()
+                .any(|(start, end)| (*start..*end).contains(&cap.start()));
+            let is_capitalized = word.starts_with(char::is_uppercase);
+            // Outside all-caps text, "Dr. Smith" and the "A" of "Plan A." keep their capitals
+            let is_name = is_capitalized
+                && (NAME_TITLES.contains(&lower.as_str())
+                    || NAME_TITLES.contains(&previous_word.to_lowercase().as_str())
+                    || (word.chars().count() == 1 && text[cap.end()..].starts_with('.')));
+            let cased = if let Some(preserved) = preserved_casing(word) {
+                preserved
+            } else if in_initialism || (!is_all_caps && ACRONYMS.read().unwrap().contains(word)) {
+                word.to_string()
+            } else if matches!(lower.as_str(), "i" | "i'm" | "i've" | "i'll" | "i'd") {
+                _capitalize(&lower, Locale::Default)
+            } else if !is_all_caps
+                && (is_name || (is_mixed_case_word(word) && word != word.to_uppercase()))
+            {
+                word.to_string()
+            } else if sentence_start {
+                _capitalize(&lower, Locale::Default)
+            } else {
+                lower
+            };
+            result.push_str(&cased);
+
+            sentence_start = false;
+            previous_word = word;
+            previous_in_initialism = in_initialism;
+            last_end = cap.end();
+        }
+        result.push_str(&text[last_end..]);
+        result
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum CaseStyle {
+        Snake,
+        ScreamingSnake,
+        Camel,
+        LowerCamel,
+        Kebab,
+        ScreamingKebab,
+        Lower,
+        Title,
+        Sentence,
+    }
+
+    const CASE_STYLES: [CaseStyle; 9] = [
+        CaseStyle::Snake,
+        CaseStyle::ScreamingSnake,
+        CaseStyle::Camel,
+        CaseStyle::LowerCamel,
+        CaseStyle::Kebab,
+        CaseStyle::ScreamingKebab,
+        CaseStyle::Lower,
+        CaseStyle::Title,
+        CaseStyle::Sentence,
+    ];
+
+    fn apply_case_style(snake: &str, style: CaseStyle) -> String {
+        match style {
+            CaseStyle::Snake => snake.to_string(),
+            CaseStyle::ScreamingSnake => snake.to_uppercase(),
+            CaseStyle::Camel => camelize(snake),
+            CaseStyle::LowerCamel => camelize_upper(snake, false),
+            CaseStyle::Kebab => dasherize(snake),
+            CaseStyle::ScreamingKebab => dasherize(snake).to_uppercase(),
+            CaseStyle::Lower => snake.replace('_', " "),
+            CaseStyle::Title => snake
+                .split('_')
+                .map(|word| _capitalize(word, Locale::Default))
+                .collect::<Vec<String>>()
+                .join(" "),
+            CaseStyle::Sentence => _capitalize(&snake.replace('_', " "), Locale::Default),
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum GrammaticalNumber {
+        Singular,
+        Plural,
+    }
+
+    fn singular_and_plural_snake(term: &str) -> (String, String) {
+        let snake = underscore(term.trim()).replace([' ', '-'], "_");
+        let singular = singularize(&snake);
+        let plural = pluralize(&singular);
+        (singular, plural)
+    }
+
+    fn identifier_forms(term: &str) -> Vec<(String, CaseStyle, GrammaticalNumber)> {
+        let (singular, plural) = singular_and_plural_snake(term);
+        let mut forms: Vec<(String, CaseStyle, GrammaticalNumber)> = Vec::new();
+        for (snake, number) in [
+            (&singular, GrammaticalNumber::Singular),
+            (&plural, GrammaticalNumber::Plural),
+        ] {
+            for style in CASE_STYLES {
+                let form = apply_case_style(snake, style);
+                if !form.is_empty() && !forms.iter().any(|(existing, _, _)| *existing == form) {
+                    forms.push((form, style, number));
+                }
+            }
+        }
+        forms
+    }
+
+    fn identifiers_regex(forms: &[(String, CaseStyle, GrammaticalNumber)]) -> Regex {
+        let mut variants: Vec<&String> = forms.iter().map(|(form, _, _)| form).collect();
+        variants.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        let pattern = variants
+            .iter()
+            .map(|variant| regex::escape(variant))
+            .collect::<Vec<String>>()
+            .join("|");
+        Regex::new(&pattern).unwrap()
+    }
+
+    fn is_identifier_boundary(text: &str, start: usize, end: usize) -> bool {
+        let matched = &text[start..end];
+        let (Some(first), Some(last)) = (matched.chars().next(), matched.chars().last()) else {
+            return false;
+        };
+        let left_ok = match text[..start].chars().next_back() {
+            None => true,
+            Some(before) if !before.is_alphanumeric() => true,
+            Some(before) => (before.is_lowercase() || before.is_numeric()) && first.is_uppercase(),
+        };
+        let right_ok = match text[end..].chars().next() {
+            None => true,
+            Some(after) if !after.is_alphanumeric() => true,
+            Some(after) => after.is_numeric() || (after.is_uppercase() && !last.is_uppercase()),
+        };
+        left_ok && right_ok
+    }
+
+    fn find_identifiers(text: &str, re: &Regex) -> Vec<(usize, usize)> {
+        let mut spans = Vec::new();
+        let mut position = 0;
+        while let Some(found) = re.find_at(text, position) {
+            if is_identifier_boundary(text, found.start(), found.end()) {
+                spans.push((found.start(), found.end()));
+                position = found.end();
+            } else {
+                let skipped = text[found.start()..].chars().next().map_or(1, char::len_utf8);
+                position = found.start() + skipped;
+            }
+        }
+        spans
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct IdentifierMatch {
+        pub start: usize,
+        pub end: usize,
+        pub text: String,
+        pub style: CaseStyle,
+        pub number: GrammaticalNumber,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct IdentifierMatcher {
+        regex: Regex,
+        forms: HashMap<String, (CaseStyle, GrammaticalNumber)>,
+    }
+
+    impl IdentifierMatcher {
+        pub fn new<S: AsRef<str>>(term: S) -> Self {
+            let forms = identifier_forms(term.as_ref());
+            IdentifierMatcher {
+                regex: identifiers_regex(&forms),
+                forms: forms
+                    .into_iter()
+                    .map(|(form, style, number)| (form, (style, number)))
+                    .collect(),
+            }
+        }
+
+        pub fn as_regex(&self) -> &Regex {
+            &self.regex
+        }
+
+        pub fn is_match<S: AsRef<str>>(&self, text: S) -> bool {
+            !self.find_all(text).is_empty()
+        }
+
+        pub fn find_all<S: AsRef<str>>(&self, text: S) -> Vec<IdentifierMatch> {
+            let text = text.as_ref();
+            if self.forms.is_empty() {
+                return Vec::new();
+            }
+            find_identifiers(text, &self.regex)
+                .into_iter()
+                .map(|(start, end)| {
+                    let (style, number) = self.forms[&text[start..end]];
+                    IdentifierMatch {
+                        start,
+                        end,
+                        text: text[start..end].to_string(),
+                        style,
+                        number,
+                    }
+                })
+                .collect()
+        }
+    }
+
+    pub fn subvert<S: AsRef<str>, T: AsRef<str>, U: AsRef<str>>(
+        text: S,
+        search: T,
+        replacement: U,
+    ) -> String {
+        let text = text.as_ref();
+        if search.as_ref().trim().is_empty() {
+            return text.to_string();
+        }
+        let matcher = IdentifierMatcher::new(search);
+        let (replacement_singular, replacement_plural) =
+            singular_and_plural_snake(replacement.as_ref());
+
+        let mut result = String::with_capacity(text.len());
+        let mut last_end = 0;
+        for found in matcher.find_all(text) {
+            let replacement = match found.number {
+                GrammaticalNumber::Singular => &replacement_singular,
+                GrammaticalNumber::Plural => &replacement_plural,
+            };
+            result.push_str(&text[last_end..found.start]);
+            result.push_str(&apply_case_style(replacement, found.style));
+            last_end = found.end;
+        }
+        result.push_str(&text[last_end..]);
+        result
+    }
+
+    pub fn normalize_spaces<S: AsRef<str>>(string: S) -> String {
+        lazy_static! {
+            static ref NS_RE: Regex = Regex::new(r"\s+").unwrap();
+        }
+        let text = string.as_ref();
+        NS_RE.replace_all(text, " ").trim().to_string()
+    }
+
+    fn _only_alpha<S: AsRef<str>>(
+        string: S,
+        check_fn: fn(c: &char) -> bool,
+        repl: Option<char>,
+    ) -> String {
+        let chars = string.as_ref().chars();
+
+        chars
+            .filter_map(|c| if !check_fn(&c) { repl } else { Some(c) })
+            .collect()
+    }
+
+    pub fn only_alpha<S: AsRef<str>>(string: S, repl: Option<char>) -> String {
+        let check_fn = |c: &char| c.is_alphabetic();
+        _only_alpha(string.as_ref(), check_fn, repl)
+    }
+
+    pub fn only_alphanum<S: AsRef<str>>(string: S, repl: Option<char>) -> String {
+        let check_fn = |c: &char| c.is_alphanumeric();
+        _only_alpha(string.as_ref(), check_fn, repl)
+    }
+
+    pub fn only_alpha_ascii<S: AsRef<str>>(string: S, repl: Option<char>) -> String {
+        let check_fn = |c: &char| c.is_ascii_alphabetic();
+        _only_alpha(string.as_ref(), check_fn, repl)
+    }
+
+    pub fn only_alphanum_ascii<S: AsRef<str>>(string: S, repl: Option<char>) -> String {
+        let check_fn = |c: &char| c.is_ascii_alphanumeric();
+        _only_alpha(string.as_ref(), check_fn, repl)
+    }
+
+    pub fn canonical_key<S: AsRef<str>>(string: S) -> String {
+        let transliterated = transliterate(string);
+        underscore(transliterated)
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(singularize)
+            .collect::<Vec<String>>()
+            .join("_")
+    }
+
+    pub fn same_identifier<S: AsRef<str>, T: AsRef<str>>(a: S, b: T) -> bool {
+        canonical_key(a) == canonical_key(b)
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Language {
+        Rust,
+        Python,
+        Go,
+        TypeScript,
+        Java,
+        Sql,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum IdentifierKind {
+        Variable,
+        Type,
+        Constant,
+    }
+
+    const RUST_KEYWORDS: [&str; 52] = [
+        "_", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+        "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+        "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv",
+        "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "try",
+        "type", "typeof", "unsafe", "unsized", "use", "virtual", "where", "while",
+    ];
+
+    // These can't be written as raw identifiers
+    const RUST_NON_RAW_KEYWORDS: [&str; 5] = ["_", "crate", "self", "Self", "super"];
+
+    const PYTHON_KEYWORDS: [&str; 35] = [
+        "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class",
+        "continue", "def", "del", "elif", "else", "except", "finally", "for", "from", "global",
+        "if", "import", "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return",
+        "try", "while", "with", "yield",
+    ];
+
+    const GO_KEYWORDS: [&str; 25] = [
+        "break", "case", "chan", "const", "continue", "default", "defer", "else", "fallthrough",
+        "for", "func", "go", "goto", "if", "import", "interface", "map", "package", "range",
+        "return", "select", "struct", "switch", "type", "var",
+    ];
+
+    const GO_INITIALISMS: [&str; 39] = [
+        "ACL", "API", "ASCII", "CPU", "CSS", "DNS", "EOF", "GID", "GUID", "HTML", "HTTP", "HTTPS",
+        "ID", "IP", "JSON", "LHS", "QPS", "RAM", "RHS", "RPC", "SLA", "SMTP", "SQL", "SSH", "TCP",
+        "TLS", "TTL", "UDP", "UI", "UID", "UUID", "URI", "URL", "UTF8", "VM", "XML", "XMPP",
+        "XSRF", "XSS",
+    ];
+
+    const TYPESCRIPT_KEYWORDS: [&str; 46] = [
+        "await", "break", "case", "catch", "class", "const", "continue", "debugger", "default",
+        "delete", "do", "else", "enum", "export", "extends", "false", "finally", "for",
+        "function", "if", "implements", "import", "in", "instanceof", "interface", "let", "new",
+        "null", "package", "private", "protected", "public", "return", "static", "super",
+        "switch", "this", "throw", "true", "try", "typeof", "var", "void", "while", "with",
+        "yield",
+    ];
+
+    const JAVA_KEYWORDS: [&str; 54] = [
+        "_", "abstract", "assert", "boolean", "break", "byte", "case", "catch", "char", "class",
+        "const", "continue", "default", "do", "double", "else", "enum", "extends", "false",
+        "final", "finally", "float", "for", "goto", "if", "implements", "import", "instanceof",
+        "int", "interface", "long", "native", "new", "null", "package", "private", "protected",
+        "public", "return", "short", "static", "strictfp", "super", "switch", "synchronized",
+        "this", "throw", "throws", "transient", "true", "try", "void", "volatile", "while",
+    ];
+
+    const SQL_KEYWORDS: [&str; 58] = [
+        "all", "alter", "and", "any", "as", "asc", "between", "by", "case", "check", "column",
+        "constraint", "create", "cross", "current_date", "current_time", "current_user",
+        "default", "delete", "desc", "distinct", "drop", "else", "end", "exists", "foreign",
+        "from", "full", "grant", "group", "having", "in", "index", "inner", "insert", "into",
+        "is", "join", "key", "left", "like", "limit", "not", "null", "offset", "on", "or",
+        "order", "primary", "references", "right", "select", "table", "then", "union", "update",
+        "user", "where",
+    ];
+
+    fn go_camelize(words: &[&str], uppercase_first_letter: bool) -> String {
+        words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| {
+                let upper = word.to_uppercase();
+                if i == 0 && !uppercase_first_letter {
+                    word.to_string()
+                } else if GO_INITIALISMS.contains(&upper.as_str()) {
+                    upper
+                } else {
+                    _capitalize(word, Locale::Default)
+                }
+            })
+            .collect()
+    }
+
+    fn escape_keyword(identifier: String, language: Language) -> String {
+        match language {
+            Language::Rust if RUST_NON_RAW_KEYWORDS.contains(&identifier.as_str()) => {
+                format!("{}_", identifier)
+            }
+            Language::Rust if RUST_KEYWORDS.contains(&identifier.as_str()) => {
+                format!("r#{}", identifier)
+            }
+            Language::Python if PYTHON_KEYWORDS.contains(&identifier.as_str()) => {
+                format!("{}_", identifier)
+            }
+            Language::Go if GO_KEYWORDS.contains(&identifier.as_str()) => {
+                format!("{}_", identifier)
+            }
+            Language::TypeScript if TYPESCRIPT_KEYWORDS.contains(&identifier.as_str()) => {
+                format!("{}_", identifier)
+            }
+            Language::Java if JAVA_KEYWORDS.contains(&identifier.as_str()) => {
+                format!("{}_", identifier)
+            }
+            Language::Sql if SQL_KEYWORDS.contains(&identifier.to_lowercase().as_str()) => {
+                format!("\"{}\"", identifier)
+            }
+            _ => identifier,
+        }
+    }
+
+    pub fn sanitize_identifier<S: AsRef<str>>(
+        name: S,
+        language: Language,
+        kind: IdentifierKind,
+    ) -> String {
+        let snake = underscore(transliterate(name));
+        let mut words: Vec<&str> = snake
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .collect();
+        if words.is_empty() {
+            words.push("unnamed");
+        }
+        let snake = words.join("_");
+
+        let style = match (language, kind) {
+            (Language::Sql, _) => CaseStyle::Snake,
+            (_, IdentifierKind::Type) => CaseStyle::Camel,
+            (Language::Go, IdentifierKind::Constant) => CaseStyle::Camel,
+            (_, IdentifierKind::Constant) => CaseStyle::ScreamingSnake,
+            (Language::Rust | Language::Python, IdentifierKind::Variable) => CaseStyle::Snake,
+            (_, IdentifierKind::Variable) => CaseStyle::LowerCamel,
+        };
+        let mut identifier = match (language, style) {
+            (Language::Go, CaseStyle::Camel) => go_camelize(&words, true),
+            (Language::Go, CaseStyle::LowerCamel) => go_camelize(&words, false),
+            _ => apply_case_style(&snake, style),
+        };
+
+        if identifier.starts_with(|c: char| c.is_ascii_digit()) {
+            identifier.insert(0, '_');
+        }
+        escape_keyword(identifier, language)
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum SqlDialect {
+        Postgres,
+        MySql,
+        Sqlite,
+        SqlServer,
+        Oracle,
+    }
+
+    impl SqlDialect {
+        pub fn max_identifier_length(&self) -> usize {
+            match self {
+                SqlDialect::Postgres => 63,
+                SqlDialect::MySql => 64,
+                SqlDialect::Sqlite => usize::MAX,
+                SqlDialect::SqlServer | SqlDialect::Oracle => 128,
+            }
+        }
+    }
+
+    fn hashed_identifier(identifier: &str) -> String {
+        Sha256::digest(identifier.as_bytes())
+            .iter()
+            .take(5)
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
+    fn truncate_bytes(string: &str, limit: usize) -> &str {
+        if string.len() <= limit {
+            return string;
+        }
+        let mut end = limit;
+        while !string.is_char_boundary(end) {
+            end -= 1;
+        }
+        &string[..end]
+    }
+
+    pub fn shorten_identifier<S: AsRef<str>>(name: S, dialect: SqlDialect) -> String {
+        let name = name.as_ref();
+        let limit = dialect.max_identifier_length();
+        if name.len() <= limit {
+            return name.to_string();
+        }
+        let suffix = format!("_{}", hashed_identifier(name));
+        let short_limit = limit.saturating_sub(suffix.len());
+        format!("{}{}", truncate_bytes(name, short_limit), suffix)
+    }
+
+    pub fn index_name<S: AsRef<str>, C: AsRef<str>>(
+        table_name: S,
+        columns: &[C],
+        dialect: SqlDialect,
+    ) -> String {
+        let columns: Vec<&str> = columns.iter().map(|column| column.as_ref()).collect();
+        let name = format!("index_{}_on_{}", table_name.as_ref(), columns.join("_and_"));
+        let limit = dialect.max_identifier_length();
+        if name.len() <= limit {
+            return name;
+        }
+
+        // Same fallback as Rails: a shorter name plus a hash of the full one
+        let suffix = format!("_{}", hashed_identifier(&name));
+        let short_name = format!("idx_on_{}", columns.join("_"));
+        let short_limit = limit.saturating_sub(suffix.len());
+        format!("{}{}", truncate_bytes(&short_name, short_limit), suffix)
+    }
+
+    pub fn foreign_key_name<S: AsRef<str>, C: AsRef<str>>(table_name: S, columns: &[C]) -> String {
+        let columns: Vec<&str> = columns.iter().map(|column| column.as_ref()).collect();
+        let identifier = format!("{}_{}_fk", table_name.as_ref(), columns.join("_and_"));
+        format!("fk_rails_{}", hashed_identifier(&identifier))
+    }
+
+    pub fn check_constraint_name<S: AsRef<str>, E: AsRef<str>>(
+        table_name: S,
+        expression: E,
+    ) -> String {
+        let identifier = format!("{}_{}_chk", table_name.as_ref(), expression.as_ref());
+        format!("chk_rails_{}", hashed_identifier(&identifier))
+    }
+
+    fn merge_join_table_names(first: &str, second: &str) -> String {
+        // "catalog_categories" and "catalog_products" share their prefix only once
+        for (index, _) in first.match_indices('_').collect::<Vec<_>>().into_iter().rev() {
+            let prefix = &first[..=index];
+            if second.starts_with(prefix) && second.len() > prefix.len() && first.len() > prefix.len()
+            {
+                return format!("{}_{}", first, &second[prefix.len()..]);
+            }
+        }
+        format!("{}_{}", first, second)
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Default)]
+    pub struct AssociationOptions {
+        pub prefix: String,
+        pub keep_namespace: bool,
+        pub dialect: Option<SqlDialect>,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum AssociationKind {
+        BelongsTo,
+        HasOne,
+        HasMany,
+        HasAndBelongsToMany,
+    }
+
+    fn association_base<S: AsRef<str>>(class_name: S, keep_namespace: bool) -> String {
+        if keep_namespace {
+            underscore(class_name).replace('/', "_")
+        } else {
+            underscore(demodulize(class_name))
+        }
+    }
+
+    pub fn join_table_name<S: AsRef<str>, T: AsRef<str>>(
+        first_model: S,
+        second_model: T,
+        dialect: SqlDialect,
+    ) -> String {
+        let options = AssociationOptions {
+            dialect: Some(dialect),
+            ..AssociationOptions::default()
+        };
+        join_table_name_with(first_model, second_model, &options)
+    }
+
+    pub fn join_table_name_with<S: AsRef<str>, T: AsRef<str>>(
+        first_model: S,
+        second_model: T,
+        options: &AssociationOptions,
+    ) -> String {
+        let mut tables = [
+            pluralize(association_base(first_model, options.keep_namespace)),
+            pluralize(association_base(second_model, options.keep_namespace)),
+        ];
+        tables.sort();
+        let name = format!(
+            "{}{}",
+            options.prefix,
+            merge_join_table_names(&tables[0], &tables[1])
+        );
+        match options.dialect {
+            Some(dialect) => shorten_identifier(name, dialect),
+            None => name,
+        }
+    }
+
+    pub fn association_name<S: AsRef<str>>(class_name: S, kind: AssociationKind) -> String {
+        association_name_with(class_name, kind, &AssociationOptions::default())
+    }
+
+    pub fn association_name_with<S: AsRef<str>>(
+        class_name: S,
+        kind: AssociationKind,
+        options: &AssociationOptions,
+    ) -> String {
+        let base = association_base(class_name, options.keep_namespace);
+        let name = match kind {
+            AssociationKind::BelongsTo | AssociationKind::HasOne => base,
+            AssociationKind::HasMany | AssociationKind::HasAndBelongsToMany => pluralize(base),
+        };
+        format!("{}{}", options.prefix, name)
+    }
+
+    pub fn through_association_name<S: AsRef<str>, T: AsRef<str>>(
+        owner_class: S,
+        target_class: T,
+    ) -> String {
+        through_association_name_with(owner_class, target_class, &AssociationOptions::default())
+    }
+
+    pub fn through_association_name_with<S: AsRef<str>, T: AsRef<str>>(
+        owner_class: S,
+        target_class: T,
+        options: &AssociationOptions,
+    ) -> String {
+        // Post has_many :tags, through: :post_tags
+        format!(
+            "{}{}_{}",
+            options.prefix,
+            association_base(owner_class, options.keep_namespace),
+            pluralize(association_base(target_class, options.keep_namespace))
+        )
+    }
+
+    pub fn inverse_association_name<S: AsRef<str>>(owner_class: S, kind: AssociationKind) -> String {
+        inverse_association_name_with(owner_class, kind, &AssociationOptions::default())
+    }
+
+    pub fn inverse_association_name_with<S: AsRef<str>>(
+        owner_class: S,
+        kind: AssociationKind,
+        options: &AssociationOptions,
+    ) -> String {
+        let inverse_kind = match kind {
+            AssociationKind::HasOne | AssociationKind::HasMany => AssociationKind::BelongsTo,
+            AssociationKind::BelongsTo => AssociationKind::HasMany,
+            AssociationKind::HasAndBelongsToMany => AssociationKind::HasAndBelongsToMany,
+        };
+        association_name_with(owner_class, inverse_kind, options)
+    }
+
+    pub trait Count: Copy {
+        fn to_count_string(self, precision: Option<usize>) -> String;
+        fn as_whole(self) -> Option<i128>;
+    }
+
+    macro_rules! impl_integer_count {
+        ($($param_type:ty),*) => {
+            $(impl Count for $param_type {
+                fn to_count_string(self, _precision: Option<usize>) -> String {
+                    self.to_string()
+                }
+
+                fn as_whole(self) -> Option<i128> {
+                    i128::try_from(self).ok()
+                }
+            })*
+        };
+    }
+
+    macro_rules! impl_float_count {
+        ($($param_type:ty),*) => {
+            $(impl Count for $param_type {
+                fn to_count_string(self, precision: Option<usize>) -> String {
+                    match precision {
+                        Some(precision) => format!("{:.*}", precision, self),
+                        None => self.to_string(),
+                    }
+                }
+
+                fn as_whole(self) -> Option<i128> {
+                    None
+                }
+            })*
+        };
+    }
+
+    impl_integer_count!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+    impl_float_count!(f32, f64);
+
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct CountOptions {
+        pub zero: Option<String>,
+        pub spell_out: bool,
+        pub thousands_separator: Option<char>,
+        pub precision: Option<usize>,
+    }
+
+    impl Default for CountOptions {
+        fn default() -> Self {
+            CountOptions {
+                zero: Some("no".to_string()),
+                spell_out: false,
+                thousands_separator: None,
+                precision: None,
+            }
+        }
+    }
+
+    const NUMBER_WORDS: [&str; 20] = [
+        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+        "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen",
+        "nineteen",
+    ];
+
+    const TENS_WORDS: [&str; 10] = [
+        "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+    ];
+
+    const SCALE_WORDS: [&str; 13] = [
+        "",
+        "thousand",
+        "million",
+        "billion",
+        "trillion",
+        "quadrillion",
+        "quintillion",
+        "sextillion",
+        "septillion",
+        "octillion",
+        "nonillion",
+        "decillion",
+        "undecillion",
+    ];
+
+    fn hundreds_to_words(number: usize) -> String {
+        let mut words = Vec::new();
+        if number >= 100 {
+            words.push(format!("{} hundred", NUMBER_WORDS[number / 100]));
+        }
+        match number % 100 {
+            0 => {}
+            rest @ 1..=19 => words.push(NUMBER_WORDS[rest].to_string()),
+            rest if rest % 10 == 0 => words.push(TENS_WORDS[rest / 10].to_string()),
+            rest => words.push(format!("{}-{}", TENS_WORDS[rest / 10], NUMBER_WORDS[rest % 10])),
+        }
+        words.join(" ")
+    }
+
+    pub fn number_to_words(number: i128) -> String {
+        if number == 0 {
+            return NUMBER_WORDS[0].to_string();
+        }
+
+        let mut remaining = number.unsigned_abs();
+        let mut groups = Vec::new();
+        let mut scale = 0;
+        while remaining > 0 {
+            let group = (remaining % 1000) as usize;
+            if group > 0 {
+                let words = hundreds_to_words(group);
+                groups.push(match SCALE_WORDS[scale] {
+                    "" => words,
+                    scale_word => format!("{} {}", words, scale_word),
+                });
+            }
+            remaining /= 1000;
+            scale += 1;
+        }
+        groups.reverse();
+
+        let words = groups.join(" ");
+        if number < 0 {
+            format!("minus {}", words)
+        } else {
+            words
+        }
+    }
+
+    fn group_digits(number: &str, separator: char) -> String {
+        let (sign, unsigned) = match number.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", number),
+        };
+        let (whole, fraction) = match unsigned.find('.') {
+            Some(index) => unsigned.split_at(index),
+            None => (unsigned, ""),
+        };
+
+        let mut grouped = String::new();
+        for (index, digit) in whole.chars().enumerate() {
+            if index > 0 && (whole.len() - index) % 3 == 0 {
+                grouped.push(separator);
+            }
+            grouped.push(digit);
+        }
+        format!("{}{}{}", sign, grouped, fraction)
+    }
+
+    pub fn pluralize_count<N: Count, S: AsRef<str>>(count: N, word: S) -> String {
+        pluralize_count_with(count, word, &CountOptions::default())
+    }
+
+    pub fn pluralize_count_with<N: Count, S: AsRef<str>>(
+        count: N,
+        word: S,
+        options: &CountOptions,
+    ) -> String {
+        let digits = count.to_count_string(options.precision);
+        let unsigned = digits.trim_start_matches('-');
+        // "1.0 miles" reads as plural, only a bare one takes the singular
+        let is_one = unsigned == "1";
+        let is_zero = unsigned.chars().all(|c| c == '0' || c == '.');
+
+        let word = if is_one {
+            singularize(word)
+        } else {
+            pluralize(word)
+        };
+
+        let number = match (&options.zero, count.as_whole()) {
+            (Some(zero), _) if is_zero => zero.clone(),
+            (_, Some(whole)) if options.spell_out => number_to_words(whole),
+            _ => match options.thousands_separator {
+                Some(separator) => group_digits(&digits, separator),
+                None => digits,
+            },
+        };
+        format!("{} {}", number, word)
+    }
+
+    const MODAL_VERBS: [&str; 10] = [
+        "can", "could", "may", "might", "must", "shall", "should", "will", "would", "ought",
+    ];
+
+    fn match_word_case(source: &str, word: String) -> String {
+        let mut letters = source.chars().filter(|c| c.is_alphabetic());
+        match (letters.next(), letters.next()) {
+            (Some(first), Some(_))
+                if first.is_uppercase() && source.chars().all(|c| !c.is_lowercase()) =>
+            {
+                word.to_uppercase()
+            }
+            (Some(first), _) if first.is_uppercase() => _capitalize(&word, Locale::Default),
+            _ => word,
+        }
+    }
+
+    fn irregular_verb(verb: &str, from_plural: bool) -> Option<String> {
+        let lower = verb.to_lowercase();
+        VERB_IRREGULARS
+            .read()
+            .unwrap()
+            .iter()
+            .find_map(|(singular, plural)| match from_plural {
+                false if *singular == lower => Some(plural.clone()),
+                true if *plural == lower => Some(singular.clone()),
+                _ if *singular == lower || *plural == lower => Some(lower.clone()),
+                _ => None,
+            })
+    }
+
+    pub fn add_irregular_verb<S: AsRef<str>, T: AsRef<str>>(singular: S, plural: T) {
+        VERB_IRREGULARS.write().unwrap().insert(
+            0,
+            (
+                singular.as_ref().to_lowercase(),
+                plural.as_ref().to_lowercase(),
+            ),
+        );
+    }
+
+    const SIBILANT_VERB_ENDINGS: [&str; 5] = ["sses", "shes", "ches", "xes", "zzes"];
+
+    const O_ES_VERBS: [&str; 4] = ["go", "do", "echo", "veto"];
+
+    pub fn remove_irregular_verb<S: AsRef<str>>(singular: S) {
+        let singular = singular.as_ref().to_lowercase();
+        VERB_IRREGULARS
+            .write()
+            .unwrap()
+            .retain(|(existing, _)| *existing != singular);
+    }
+
+    fn is_third_person_s(lower: &str) -> bool {
+        lower.ends_with('s') && !["ss", "us", "is"].iter().any(|suffix| lower.ends_with(suffix))
+    }
+
+    pub fn pluralize_verb<S: AsRef<str>>(verb: S) -> String {
+        let verb = verb.as_ref();
+        let lower = verb.to_lowercase();
+        if let Some(plural) = irregular_verb(verb, false) {
+            return match_word_case(verb, plural);
+        }
+        if MODAL_VERBS.contains(&lower.as_str()) {
+            return verb.to_string();
+        }
+
+        let plural = if lower.ends_with("ies") && lower.len() > 4 {
+            format!("{}y", &lower[..lower.len() - 3])
+        } else if SIBILANT_VERB_ENDINGS.iter().any(|suffix| lower.ends_with(suffix))
+            || lower
+                .strip_suffix("es")
+                .is_some_and(|stem| O_ES_VERBS.contains(&stem))
+        {
+            lower[..lower.len() - 2].to_string()
+        } else if is_third_person_s(&lower) {
+            lower[..lower.len() - 1].to_string()
+        } else {
+            return verb.to_string();
+        };
+        match_word_case(verb, plural)
+    }
+
+    pub fn singularize_verb<S: AsRef<str>>(verb: S) -> String {
+        let verb = verb.as_ref();
+        let lower = verb.to_lowercase();
+        if let Some(singular) = irregular_verb(verb, true) {
+            return match_word_case(verb, singular);
+        }
+        if MODAL_VERBS.contains(&lower.as_str()) || lower.is_empty() || is_third_person_s(&lower) {
+            return verb.to_string();
+        }
+
+        let consonant_y = lower.len() > 1
+            && lower.ends_with('y')
+            && !lower[..lower.len() - 1].ends_with(['a', 'e', 'i', 'o', 'u']);
+        if consonant_y {
+            return match_word_case(verb, format!("{}ies", &lower[..lower.len() - 1]));
+        }
+
+        let suffix = if ["s", "sh", "ch", "x", "zz"].iter().any(|suffix| lower.ends_with(suffix))
+            || O_ES_VERBS.contains(&lower.as_str())
+        {
+            "es"
+        } else {
+            "s"
+        };
+        match_word_case(verb, format!("{}{}", lower, suffix))
+    }
+
+    fn irregular_conjugation(verb: &str) -> Option<(String, String)> {
+        let lower = verb.to_lowercase();
+        VERB_CONJUGATIONS
+            .read()
+            .unwrap()
+            .iter()
+            .find(|(base, _, _)| *base == lower)
+            .map(|(_, past, participle)| (past.clone(), participle.clone()))
+    }
+
+    fn conjugate(verb: &str, rules: &[(Regex, String)]) -> String {
+        let lower = verb.to_lowercase();
+        for (rule, repl) in rules {
+            if rule.is_match(&lower) {
+                return match_word_case(verb, rule.replace(&lower, repl.as_str()).to_string());
+            }
+        }
+        verb.to_string()
+    }
+
+    pub fn add_irregular_conjugation<S: AsRef<str>, T: AsRef<str>, U: AsRef<str>>(
+        base: S,
+        past: T,
+        past_participle: U,
+    ) {
+        VERB_CONJUGATIONS.write().unwrap().insert(
+            0,
+            (
+                base.as_ref().to_lowercase(),
+                past.as_ref().to_lowercase(),
+                past_participle.as_ref().to_lowercase(),
+            ),
+        );
+    }
+
+    pub fn remove_irregular_conjugation<S: AsRef<str>>(base: S) {
+        let base = base.as_ref().to_lowercase();
+        VERB_CONJUGATIONS
+            .write()
+            .unwrap()
+            .retain(|(existing, _, _)| *existing != base);
+    }
+
+    pub fn past_tense<S: AsRef<str>>(verb: S) -> String {
+        let verb = verb.as_ref();
+        if verb.is_empty() {
+            return String::new();
+        }
+        match irregular_conjugation(verb) {
+            Some((past, _)) => match_word_case(verb, past),
+            None => conjugate(verb, &CONJUGATION_RULES.0),
+        }
+    }
+
+    pub fn past_participle<S: AsRef<str>>(verb: S) -> String {
+        let verb = verb.as_ref();
+        match irregular_conjugation(verb) {
+            Some((_, participle)) => match_word_case(verb, participle),
+            None => past_tense(verb),
+        }
+    }
+
+    pub fn present_participle<S: AsRef<str>>(verb: S) -> String {
+        let verb = verb.as_ref();
+        if verb.is_empty() {
+            return String::new();
+        }
+        conjugate(verb, &CONJUGATION_RULES.1)
+    }
+
+    pub fn third_person<S: AsRef<str>>(verb: S) -> String {
+        let verb = verb.as_ref();
+        if verb.eq_ignore_ascii_case("be") {
+            return match_word_case(verb, "is".to_string());
+        }
+        singularize_verb(verb)
+    }
+
+    fn _plural_adjective(adjective: &str) -> String {
+        let plural = match adjective.to_lowercase().as_str() {
+            "a" | "an" => "some",
+            "this" => "these",
+            "that" => "those",
+            "my" => "our",
+            "its" | "his" | "her" => "their",
+            "itself" | "himself" | "herself" => "themselves",
+            _ => return adjective.to_string(),
+        };
+        if adjective.chars().next().is_some_and(char::is_uppercase) {
+            _capitalize(plural, Locale::Default)
+        } else {
+            plural.to_string()
+        }
+    }
+
+    pub fn add_indefinite_article<S: AsRef<str>, T: AsRef<str>>(word: S, article: T) {
+        ARTICLE_OVERRIDES
+            .write()
+            .unwrap()
+            .insert(word.as_ref().to_lowercase(), article.as_ref().to_string());
+    }
+
+    pub fn remove_indefinite_article<S: AsRef<str>>(word: S) {
+        ARTICLE_OVERRIDES
+            .write()
+            .unwrap()
+            .remove(&word.as_ref().to_lowercase());
+    }
+
+    pub fn clear_indefinite_articles() {
+        ARTICLE_OVERRIDES.write().unwrap().clear();
+    }
+
+    fn number_takes_an(digits: &str) -> bool {
+        // "eight", "eleven" and "eighteen" are the only leading sounds that need "an"
+        digits.starts_with('8')
+            || (digits.len() % 3 == 2 && (digits.starts_with("11") || digits.starts_with("18")))
+    }
+
+    // Acronyms pronounced as words rather than letter by letter
+    const SPOKEN_ACRONYMS: [&str; 4] = ["NASA", "NATO", "UNESCO", "UNICEF"];
+
+    pub fn indefinite_article<S: AsRef<str>>(word: S) -> String {
+        lazy_static! {
+            static ref SILENT_H_RE: Regex =
+                Regex::new(r"^(?:hour|honou?r|honest|heir)").unwrap();
+            static ref CONSONANT_SOUND_RE: Regex = Regex::new(
+                r"^(?:eu|ewe|uni(?:[^dmn]|d[^e]|$)|u[bcfhjkqrst][aeiou]|uu|onc?e(?:[^a-z]|self|$))"
+            )
+            .unwrap();
+        }
+        let word = word.as_ref().trim();
+        let first = word
+            .split(|c: char| c.is_whitespace() || c == '-')
+            .next()
+            .unwrap_or_default();
+
+        if let Some(article) = ARTICLE_OVERRIDES.read().unwrap().get(&first.to_lowercase()) {
+            return article.clone();
+        }
+
+        let lower = first.to_lowercase();
+        // Capitals are only spelled out when they're a known acronym or can't be read as a word
+        let is_initialism = first.chars().count() == 1
+            || (ACRONYMS.read().unwrap().contains(first) && !SPOKEN_ACRONYMS.contains(&first))
+            || (first.chars().all(|c| c.is_uppercase() || c.is_ascii_digit())
+                && !lower.contains(['a', 'e', 'i', 'o', 'u']));
+        let takes_an = if first.starts_with(|c: char| c.is_ascii_digit()) {
+            let digits: String = first
+                .chars()
+                .take_while(|c| c.is_ascii_digit() || *c == ',')
+                .filter(char::is_ascii_digit)
+                .collect();
+            number_takes_an(&digits)
+        } else if is_initialism {
+            // Initialisms are spelled out, so the first letter's name decides
+            lower.starts_with(['a', 'e', 'f', 'h', 'i', 'l', 'm', 'n', 'o', 'r', 's', 'x'])
+        } else {
+            SILENT_H_RE.is_match(&lower)
+                || (lower.starts_with(['a', 'e', 'i', 'o', 'u'])
+                    && !CONSONANT_SOUND_RE.is_match(&lower))
+        };
+
+        if takes_an { "an" } else { "a" }.to_string()
+    }
+
+    pub fn with_article<S: AsRef<str>>(word: S) -> String {
+        let word = word.as_ref();
+        format!("{} {}", indefinite_article(word), word)
+    }
+
+    pub fn add_irregular_adjective<S: AsRef<str>, T: AsRef<str>, U: AsRef<str>>(
+        base: S,
+        comparative: T,
+        superlative: U,
+    ) {
+        ADJECTIVE_IRREGULARS.write().unwrap().insert(
+            0,
+            (
+                base.as_ref().to_lowercase(),
+                comparative.as_ref().to_lowercase(),
+                superlative.as_ref().to_lowercase(),
+            ),
+        );
+    }
+
+    pub fn remove_irregular_adjective<S: AsRef<str>>(base: S) {
+        let base = base.as_ref().to_lowercase();
+        ADJECTIVE_IRREGULARS
+            .write()
+            .unwrap()
+            .retain(|(existing, _, _)| *existing != base);
+    }
+
+    fn count_syllables(word: &str) -> usize {
+        let mut syllables = 0;
+        let mut previous_vowel = false;
+        for c in word.chars() {
+            let vowel = "aeiouy".contains(c);
+            if vowel && !previous_vowel {
+                syllables += 1;
+            }
+            previous_vowel = vowel;
+        }
+        let silent_e = word.ends_with('e')
+            && !["le", "ee", "ye"].iter().any(|suffix| word.ends_with(suffix));
+        if silent_e && syllables > 1 {
+            syllables -= 1;
+        }
+        syllables
+    }
+
+    fn takes_more_and_most(adjective: &str) -> bool {
+        if adjective.contains(char::is_whitespace)
+            || (adjective.len() > 4 && (adjective.ends_with("ed") || adjective.ends_with("ing")))
+        {
+            return true;
+        }
+        match count_syllables(adjective) {
+            0 | 1 => false,
+            2 => !["y", "le", "er", "ow"].iter().any(|suffix| adjective.ends_with(suffix)),
+            _ => true,
+        }
+    }
+
+    fn compare_adjective(adjective: &str, superlative: bool) -> String {
+        if adjective.is_empty() {
+            return String::new();
+        }
+        let lower = adjective.to_lowercase();
+        let irregular = ADJECTIVE_IRREGULARS
+            .read()
+            .unwrap()
+            .iter()
+            .find(|(base, _, _)| *base == lower)
+            .map(|(_, comparative, superlative_form)| {
+                if superlative { superlative_form.clone() } else { comparative.clone() }
+            });
+        if let Some(form) = irregular {
+            return match_word_case(adjective, form);
+        }
+
+        if takes_more_and_most(&lower) {
+            let degree = if superlative { "most" } else { "more" };
+            return match_word_case(adjective, format!("{} {}", degree, lower));
+        }
+
+        let rules = if superlative { &ADJECTIVE_RULES.1 } else { &ADJECTIVE_RULES.0 };
+        for (rule, repl) in rules {
+            if rule.is_match(&lower) {
+                return match_word_case(adjective, rule.replace(&lower, repl.as_str()).to_string());
+            }
+        }
+        adjective.to_string()
+    }
+
+    pub fn comparative<S: AsRef<str>>(adjective: S) -> String {
+        compare_adjective(adjective.as_ref(), false)
+    }
+
+    pub fn superlative<S: AsRef<str>>(adjective: S) -> String {
+        compare_adjective(adjective.as_ref(), true)
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub enum PossessiveStyle {
+        #[default]
+        Chicago,
+        Ap,
+    }
+
+    // Names that would otherwise read as plurals of "jame", "charle" and so on
+    const NAMES_ENDING_IN_S: [&str; 16] = [
+        "adams", "athens", "charles", "davies", "dickens", "douglas", "edwards", "evans",
+        "hughes", "james", "jones", "lucas", "nicholas", "texas", "thomas", "williams",
+    ];
+
+    fn is_plural_noun(noun: &str) -> bool {
+        let lower = noun.to_lowercase();
+        if get_uncountable().contains(lower.as_str())
+            || ["ss", "us", "is"].iter().any(|suffix| lower.ends_with(suffix))
+        {
+            return false;
+        }
+        let singular = singularize(&lower);
+        singular != lower && pluralize(&singular) == lower
+    }
+
+    pub fn possessive<S: AsRef<str>>(noun: S) -> String {
+        possessive_with_style(noun, PossessiveStyle::default())
+    }
+
+    pub fn possessive_with_style<S: AsRef<str>>(noun: S, style: PossessiveStyle) -> String {
+        let noun = noun.as_ref();
+        if noun.is_empty() || noun.ends_with("'s") || noun.ends_with("s'") {
+            return noun.to_string();
+        }
+        if !noun.ends_with(['s', 'S']) {
+            return format!("{}'s", noun);
+        }
+
+        let is_plural = !NAMES_ENDING_IN_S.contains(&noun.to_lowercase().as_str())
+            && is_plural_noun(noun);
+        let is_name = noun.chars().next().is_some_and(char::is_uppercase)
+            && noun.chars().any(char::is_lowercase);
+        if is_plural || (is_name && style == PossessiveStyle::Ap) {
+            format!("{}'", noun)
+        } else {
+            format!("{}'s", noun)
+        }
+    }
+
+    pub fn plural_possessive<S: AsRef<str>>(noun: S) -> String {
+        let plural = pluralize(noun);
+        if plural.ends_with(['s', 'S']) {
+            format!("{}'", plural)
+        } else {
+            format!("{}'s", plural)
+        }
+    }
+
+    fn is_singular_count(count: &str) -> bool {
+        matches!(
+            count.trim_start_matches('-').to_lowercase().as_str(),
+            "1" | "one" | "a" | "an"
+        )
+    }
+
+    pub fn inflect<S: AsRef<str>>(template: S) -> String {
+        lazy_static! {
+            static ref INFLECT_RE: Regex =
+                Regex::new(r"\b(PL_N|PL_V|PL_ADJ|PL|NO|NUMWORDS|NUM|ORD|AN|A)\(([^()]*)\)").unwrap();
+        }
+        let mut default_count: Option<String> = None;
+
+        INFLECT_RE
+            .replace_all(template.as_ref(), |caps: &Captures| {
+                let args: Vec<&str> = caps[2].split(',').map(str::trim).collect();
+                if args.len() > 2 || args.iter().any(|arg| arg.is_empty()) {
+                    return caps[0].to_string();
+                }
+                let word = args[0];
+                let count = args
+                    .get(1)
+                    .map(|count| count.to_string())
+                    .or_else(|| default_count.clone());
+                let singular = count.as_deref().is_some_and(is_singular_count);
+
+                match &caps[1] {
+                    "PL" | "PL_N" if singular => singularize(word),
+                    "PL" | "PL_N" => pluralize(word),
+                    "PL_V" if singular => singularize_verb(word),
+                    "PL_V" => pluralize_verb(word),
+                    "PL_ADJ" if singular => word.to_string(),
+                    "PL_ADJ" => _plural_adjective(word),
+                    "NO" => match count.as_deref() {
+                        None => format!("no {}", pluralize(word)),
+                        Some(count) => match (count.parse::<i128>(), count.parse::<f64>()) {
+                            (Ok(value), _) => pluralize_count(value, word),
+                            (_, Ok(value)) => pluralize_count(value, word),
+                            _ => caps[0].to_string(),
+                        },
+                    },
+                    "NUM" => {
+                        default_count = Some(word.to_string());
+                        word.to_string()
+                    }
+                    "NUMWORDS" => match word.parse::<i128>() {
+                        Ok(number) => number_to_words(number),
+                        Err(_) => caps[0].to_string(),
+                    },
+                    "ORD" => match word.parse::<i128>() {
+                        Ok(number) => ordinalize_i128(number),
+                        Err(_) => caps[0].to_string(),
+                    },
+                    _ => with_article(word),
+                }
+            })
+            .to_string()
     }
 
     pub fn keyify<S: AsRef<str>>(string: S) -> String {
         let result = only_alphanum_ascii(string, Some(' '));
         let result = normalize_spaces(result);
-        let result = titleize(result);
+        let result = _titleize(&result, &HumanizeOptions::default(), false);
         let result = parameterize_with_sep(result, "_".to_string());
         underscore(result.trim())
     }
@@ -645,7 +3041,20 @@ pub mod inflection {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
     use crate::inflection;
+    use crate::inflection::Locale;
+
+    // Tests that register words hold the write lock; tests whose tables use those words read it
+    static REGISTRY: RwLock<()> = RwLock::new(());
+
+    fn registry_read() -> RwLockReadGuard<'static, ()> {
+        REGISTRY.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn registry_write() -> RwLockWriteGuard<'static, ()> {
+        REGISTRY.write().unwrap_or_else(PoisonError::into_inner)
+    }
 
     const SINGULAR_TO_PLURAL: [(&str, &str); 90] = [
         ("search", "searches"),
@@ -754,6 +3163,168 @@ mod tests {
         ("HTML", "html"),
     ];
 
+    const UNICODE_CAMEL_TO_UNDERSCORE: [(&str, &str); 8] = [
+        ("ÉcoleNormale", "école_normale"),
+        ("ÀLaCarte", "à_la_carte"),
+        ("ΚαλημέραΚόσμε", "καλημέρα_κόσμε"),
+        ("ПриветМир", "привет_мир"),
+        ("ǅunglaǅep", "ǆungla_ǆep"),
+        ("日本語", "日本語"),
+        ("UserAccountIdValue", "user_account_id_value"),
+        ("StraßeNummer", "straße_nummer"),
+    ];
+
+    const UNICODE_LOWER_CAMEL_TO_UNDERSCORE: [(&str, &str); 4] = [
+        ("écoleNormale", "école_normale"),
+        ("καλημέραΚόσμε", "καλημέρα_κόσμε"),
+        ("приветМир", "привет_мир"),
+        ("日本語", "日本語"),
+    ];
+
+    const UNICODE_TO_TITLEIZED: [(&str, &str); 7] = [
+        ("école normale", "École Normale"),
+        ("ÉcoleNormale", "École Normale"),
+        ("ǆungla", "ǅungla"),
+        ("ﬁnal ﬂight", "Final Flight"),
+        ("καλημέρα κόσμε", "Καλημέρα Κόσμε"),
+        ("привет мир", "Привет Мир"),
+        ("日本語 テキスト", "日本語 テキスト"),
+    ];
+
+    const UNICODE_TO_HUMAN: [(&str, &str); 4] = [
+        ("école_normale", "École normale"),
+        ("ΚΑΛΗΜΕΡΑ_ΚΟΣΜΟΣ", "Καλημερα κοσμος"),
+        ("ПРИВЕТ_МИР_id", "Привет мир"),
+        ("日本_語", "日本 語"),
+    ];
+
+    const TURKISH_UPPER_TO_LOWER: [(&str, &str); 4] = [
+        ("İSTANBUL", "istanbul"),
+        ("DİYARBAKIR", "diyarbakır"),
+        ("IŞIK", "ışık"),
+        ("I\u{307}", "i"),
+    ];
+
+    const LITHUANIAN_UPPER_TO_LOWER: [(&str, &str); 5] = [
+        ("Ì", "i\u{307}\u{300}"),
+        ("Í", "i\u{307}\u{301}"),
+        ("Ĩ", "i\u{307}\u{303}"),
+        ("I\u{300}", "i\u{307}\u{300}"),
+        ("Į\u{301}", "į\u{307}\u{301}"),
+    ];
+
+    const TURKISH_TO_TITLEIZED: [(&str, &str); 3] = [
+        ("izmir ilçesi", "İzmir İlçesi"),
+        ("ırmak kıyısı", "Irmak Kıyısı"),
+        ("İstanbulİzmir", "İstanbul İzmir"),
+    ];
+
+    const STRING_TO_CHICAGO_TITLE: [(&str, &str); 11] = [
+        ("the lord of the rings", "The Lord of the Rings"),
+        ("THE LORD OF THE RINGS", "The Lord of the Rings"),
+        ("gone with the wind", "Gone with the Wind"),
+        ("sleeping through the night", "Sleeping through the Night"),
+        ("what are you looking for", "What Are You Looking For"),
+        ("star wars: a new hope", "Star Wars: A New Hope"),
+        ("state-of-the-art design", "State-of-the-Art Design"),
+        ("raiders_of_the_lost_ark", "Raiders of the Lost Ark"),
+        ("TheManWithoutAPast", "The Man without a Past"),
+        ("the sun also rises yet again", "The Sun Also Rises Yet Again"),
+        ("the NASA report on the FBI", "The NASA Report on the FBI"),
+    ];
+
+    const STRING_TO_AP_TITLE: [(&str, &str); 6] = [
+        ("the lord of the rings", "The Lord of the Rings"),
+        ("gone with the wind", "Gone With the Wind"),
+        ("sleeping through the night", "Sleeping Through the Night"),
+        ("star wars: a new hope", "Star Wars: A New Hope"),
+        ("TheManWithoutAPast", "The Man Without a Past"),
+        ("x-men: the last stand", "X-Men: The Last Stand"),
+    ];
+
+    const STRING_TO_MLA_TITLE: [(&str, &str); 5] = [
+        ("gone with the wind", "Gone with the Wind"),
+        ("the sun also rises yet again", "The Sun Also Rises yet Again"),
+        ("so you want to be a wizard", "So You Want to Be a Wizard"),
+        ("editors-in-chief and the press", "Editors-in-Chief and the Press"),
+        ("\"the raven\" and other poems", "\"The Raven\" and Other Poems"),
+    ];
+
+    const STRING_TO_SENTENCE_CASE: [(&str, &str); 15] = [
+        ("ANNUAL REPORT FOR THE YEAR", "Annual report for the year"),
+        (
+            "Annual Report For The Year. Second Part",
+            "Annual report for the year. Second part",
+        ),
+        ("The FBI Mission Overview", "The FBI mission overview"),
+        ("The NASA Mission Overview", "The NASA mission overview"),
+        ("iPhone sales ROSE.", "iPhone sales rose."),
+        ("the U.S. economy. it grew", "The U.S. economy. It grew"),
+        ("ask Dr. Smith. then leave", "Ask Dr. Smith. Then leave"),
+        ("ASK DR. SMITH. THEN LEAVE", "Ask dr. smith. Then leave"),
+        ("Plan A. Then B. ok", "Plan A. Then B. Ok"),
+        ("WHAT I SAW. WHERE I WENT!", "What I saw. Where I went!"),
+        ("Learning JavaScript The Hard Way", "Learning JavaScript the hard way"),
+        ("hello world? yes, hello", "Hello world? Yes, hello"),
+        ("Dinner At McDonald's", "Dinner at McDonald's"),
+        ("  leading whitespace  ", "  Leading whitespace  "),
+        ("", ""),
+    ];
+
+    const FACILITY_TO_BUILDING: [(&str, &str); 12] = [
+        ("facility", "building"),
+        ("Facility", "Building"),
+        ("Facilities", "Buildings"),
+        ("FACILITY_ID", "BUILDING_ID"),
+        ("FACILITIES", "BUILDINGS"),
+        ("facilityName", "buildingName"),
+        ("myFacilityName", "myBuildingName"),
+        ("FacilityID", "BuildingID"),
+        ("the facilities-list", "the buildings-list"),
+        ("facility_2", "building_2"),
+        ("refacility", "refacility"),
+        ("facilitys", "facilitys"),
+    ];
+
+    const STRING_TO_CANONICAL_KEY: [(&str, &str); 12] = [
+        ("Categories", "category"),
+        ("category", "category"),
+        ("CATEGORY", "category"),
+        ("UserAccounts", "user_account"),
+        ("user-accounts", "user_account"),
+        ("USER_ACCOUNT", "user_account"),
+        ("  User Account  ", "user_account"),
+        ("HTTPRequests", "http_request"),
+        ("status_codes", "status_code"),
+        ("Crème Brûlées", "creme_brulee"),
+        ("Admin::People", "admin_person"),
+        ("", ""),
+    ];
+
+    const CAMEL_WITH_MODULE_TO_UNDERSCORE_WITH_SLASH: [(&str, &str); 3] = [
+        ("Admin::Product", "admin/product"),
+        ("Users::Commission::Department", "users/commission/department"),
+        ("UsersSection::CommissionDepartment", "users_section/commission_department"),
+    ];
+
+    const PATH_TO_DEMODULIZED: [(&str, &str); 5] = [
+        ("MyApplication::Billing::Account", "Account"),
+        ("Account", "Account"),
+        ("::Account", "Account"),
+        ("crate::models::User", "User"),
+        ("", ""),
+    ];
+
+    const PATH_TO_DECONSTANTIZED: [(&str, &str); 7] = [
+        ("MyApplication::Billing::Account", "MyApplication::Billing"),
+        ("::MyApplication::Billing::Account", "::MyApplication::Billing"),
+        ("MyApplication::Billing", "MyApplication"),
+        ("::MyApplication::Billing", "::MyApplication"),
+        ("Account", ""),
+        ("::Account", ""),
+        ("", ""),
+    ];
+
     const STRING_TO_PARAMETERIZED: [(&str, &str); 8] = [
         (r"Donald E. Knuth", "donald-e-knuth"),
         (
@@ -839,10 +3410,47 @@ mod tests {
         (r"日本語", "ri-ben-yu"),
     ];
 
-    const UNDERSCORE_TO_HUMAN: [(&str, &str); 3] = [
+    const UNDERSCORE_TO_HUMAN: [(&str, &str); 5] = [
         ("employee_salary", "Employee salary"),
         ("employee_id", "Employee"),
         ("underground", "Underground"),
+        ("_id", "Id"),
+        ("_external_id", "External"),
+    ];
+
+    const UNDERSCORE_TO_HUMAN_WITHOUT_CAPITALIZE: [(&str, &str); 3] = [
+        ("employee_salary", "employee salary"),
+        ("employee_id", "employee"),
+        ("underground", "underground"),
+    ];
+
+    const UNDERSCORE_TO_HUMAN_WITH_KEEP_ID_SUFFIX: [(&str, &str); 6] = [
+        (
+            "this_is_a_string_ending_with_id",
+            "This is a string ending with id",
+        ),
+        ("employee_id", "Employee id"),
+        ("employee_id_something_else", "Employee id something else"),
+        ("underground", "Underground"),
+        ("_id", "Id"),
+        ("_external_id", "External id"),
+    ];
+
+    const CLASS_NAME_TO_TABLE_NAME: [(&str, &str); 2] = [
+        ("PrimarySpokesman", "primary_spokesmen"),
+        ("NodeChild", "node_children"),
+    ];
+
+    const CLASS_NAME_TO_FOREIGN_KEY_WITH_UNDERSCORE: [(&str, &str); 3] = [
+        ("Person", "person_id"),
+        ("MyApplication::Billing::Account", "account_id"),
+        ("Message", "message_id"),
+    ];
+
+    const CLASS_NAME_TO_FOREIGN_KEY_WITHOUT_UNDERSCORE: [(&str, &str); 3] = [
+        ("Person", "personid"),
+        ("MyApplication::Billing::Account", "accountid"),
+        ("Message", "messageid"),
     ];
 
     const MIXTURE_TO_TITLEIZED: [(&str, &str); 12] = [
@@ -885,6 +3493,171 @@ mod tests {
         }
     }
 
+    #[test]
+    fn unicode_camelize_bulk() {
+        for (expected, input) in UNICODE_CAMEL_TO_UNDERSCORE {
+            assert_eq!(inflection::camelize(input), expected);
+        }
+        for (expected, input) in UNICODE_LOWER_CAMEL_TO_UNDERSCORE {
+            assert_eq!(inflection::camelize_upper(input, false), expected);
+        }
+        assert_eq!(inflection::camelize("a_b_c"), "ABC");
+        assert_eq!(inflection::camelize("ß_ﬁ"), "SsFi");
+    }
+
+    #[test]
+    fn unicode_underscore_bulk() {
+        for (input, expected) in UNICODE_CAMEL_TO_UNDERSCORE {
+            assert_eq!(inflection::underscore(input), expected);
+        }
+        for (input, expected) in UNICODE_LOWER_CAMEL_TO_UNDERSCORE {
+            assert_eq!(inflection::underscore(input), expected);
+        }
+    }
+
+    #[test]
+    fn unicode_titleize_bulk() {
+        for (input, expected) in UNICODE_TO_TITLEIZED {
+            assert_eq!(inflection::titleize(input), expected);
+        }
+    }
+
+    #[test]
+    fn unicode_humanize_bulk() {
+        for (input, expected) in UNICODE_TO_HUMAN {
+            assert_eq!(inflection::humanize(input), expected);
+        }
+    }
+
+    #[test]
+    fn locale_lowercase_bulk() {
+        for (input, expected) in TURKISH_UPPER_TO_LOWER {
+            assert_eq!(inflection::to_lowercase_with_locale(input, Locale::Turkish), expected);
+            assert_eq!(inflection::to_lowercase_with_locale(input, Locale::Azeri), expected);
+        }
+        for (input, expected) in LITHUANIAN_UPPER_TO_LOWER {
+            assert_eq!(
+                inflection::to_lowercase_with_locale(input, Locale::Lithuanian),
+                expected
+            );
+        }
+        assert_eq!(inflection::to_lowercase_with_locale("İ", Locale::Default), "i\u{307}");
+        assert_eq!(inflection::to_lowercase_with_locale("STRAẞE", Locale::German), "straße");
+        assert_eq!(inflection::to_lowercase_with_locale("ΟΔΟΣ", Locale::Turkish), "οδος");
+    }
+
+    #[test]
+    fn locale_uppercase_bulk() {
+        for (expected, input) in TURKISH_UPPER_TO_LOWER.iter().take(3) {
+            assert_eq!(inflection::to_uppercase_with_locale(input, Locale::Turkish), *expected);
+        }
+        for (_, input) in LITHUANIAN_UPPER_TO_LOWER {
+            assert!(
+                !inflection::to_uppercase_with_locale(input, Locale::Lithuanian)
+                    .contains('\u{307}')
+            );
+        }
+        assert_eq!(inflection::to_uppercase_with_locale("istanbul", Locale::Default), "ISTANBUL");
+        assert_eq!(inflection::to_uppercase_with_locale("straße", Locale::German), "STRAẞE");
+        assert_eq!(inflection::to_uppercase_with_locale("straße", Locale::Default), "STRASSE");
+    }
+
+    #[test]
+    fn locale_titleize_bulk() {
+        for (input, expected) in TURKISH_TO_TITLEIZED {
+            assert_eq!(inflection::titleize_with_locale(input, Locale::Turkish), expected);
+        }
+        assert_eq!(inflection::titleize("izmir ilçesi"), "Izmir Ilçesi");
+        assert_eq!(
+            inflection::titleize_with_locale("große straße", Locale::German),
+            "Große Straße"
+        );
+    }
+
+    #[test]
+    fn locale_case_conversions() {
+        assert_eq!(inflection::capitalize_with_locale("istanbul", Locale::Turkish), "İstanbul");
+        assert_eq!(inflection::capitalize_with_locale("istanbul", Locale::Default), "Istanbul");
+        assert_eq!(
+            inflection::capitalize_with_locale("i\u{307}\u{300}s", Locale::Lithuanian),
+            "I\u{300}s"
+        );
+        assert_eq!(
+            inflection::underscore_with_locale("İstanbulİzmir", Locale::Turkish),
+            "istanbul_izmir"
+        );
+        assert_eq!(
+            inflection::underscore_with_locale("IrmakIşık", Locale::Turkish),
+            "ırmak_ışık"
+        );
+        assert_eq!(
+            inflection::camelize_with_locale("istanbul_izmir", true, Locale::Turkish),
+            "İstanbulİzmir"
+        );
+        assert_eq!(
+            inflection::camelize_with_locale("istanbul_izmir", false, Locale::Turkish),
+            "istanbulİzmir"
+        );
+        assert_eq!(
+            inflection::humanize_with(
+                "IRMAK_KIYISI",
+                &inflection::HumanizeOptions {
+                    locale: Locale::Turkish,
+                    ..Default::default()
+                }
+            ),
+            "Irmak kıyısı"
+        );
+    }
+
+    #[test]
+    fn camelize_with_module_bulk() {
+        for (expected, input) in CAMEL_WITH_MODULE_TO_UNDERSCORE_WITH_SLASH {
+            assert_eq!(inflection::camelize(input), expected);
+        }
+        assert_eq!(
+            inflection::camelize_upper("admin/user_account", false),
+            "admin::UserAccount"
+        );
+        assert_eq!(
+            inflection::camelize_with_sep("models/user_account", true, ".".to_string()),
+            "Models.UserAccount"
+        );
+    }
+
+    #[test]
+    fn underscore_with_module_bulk() {
+        for (input, expected) in CAMEL_WITH_MODULE_TO_UNDERSCORE_WITH_SLASH {
+            assert_eq!(inflection::underscore(input), expected);
+        }
+        assert_eq!(
+            inflection::underscore_with_sep("Models.UserAccount", ".".to_string()),
+            "models/user_account"
+        );
+    }
+
+    #[test]
+    fn demodulize_bulk() {
+        for (input, expected) in PATH_TO_DEMODULIZED {
+            assert_eq!(inflection::demodulize(input), expected);
+        }
+        assert_eq!(
+            inflection::demodulize_with_sep("app.models.User", ".".to_string()),
+            "User"
+        );
+    }
+
+    #[test]
+    fn deconstantize_bulk() {
+        for (input, expected) in PATH_TO_DECONSTANTIZED {
+            assert_eq!(inflection::deconstantize(input), expected);
+        }
+        assert_eq!(
+            inflection::deconstantize_with_sep("app.models.User", ".".to_string()),
+            "app.models"
+        );
+    }
+
     #[test]
     fn pluralize_bulk() {
         for (input, expected) in SINGULAR_TO_PLURAL {
@@ -926,18 +3699,1029 @@ mod tests {
 
     #[test]
     fn humanize_bulk() {
+        let _registry = registry_read();
         for (input, expected) in UNDERSCORE_TO_HUMAN {
             assert_eq!(inflection::humanize(input), expected);
         }
     }
 
+    #[test]
+    fn humanize_with_bulk() {
+        let without_capitalize = inflection::HumanizeOptions {
+            capitalize: false,
+            ..Default::default()
+        };
+        for (input, expected) in UNDERSCORE_TO_HUMAN_WITHOUT_CAPITALIZE {
+            assert_eq!(inflection::humanize_with(input, &without_capitalize), expected);
+        }
+
+        let keep_id_suffix = inflection::HumanizeOptions {
+            keep_id_suffix: true,
+            ..Default::default()
+        };
+        for (input, expected) in UNDERSCORE_TO_HUMAN_WITH_KEEP_ID_SUFFIX {
+            assert_eq!(inflection::humanize_with(input, &keep_id_suffix), expected);
+        }
+    }
+
+    #[test]
+    fn humanize_with_human_rules() {
+        let _registry = registry_write();
+        inflection::add_human(r"_cnt$", "_count").unwrap();
+        inflection::add_human(r"^legacy_col_person_name$", "Name").unwrap();
+
+        assert_eq!(inflection::humanize("jobs_cnt"), "Jobs count");
+        assert_eq!(inflection::humanize("legacy_col_person_name"), "Name");
+        assert_eq!(inflection::titleize("jobs_cnt"), "Jobs Count");
+        assert_eq!(inflection::titleize("JobsCnt"), "Jobs Count");
+        assert!(inflection::add_human(r"(unclosed", "x").is_err());
+
+        inflection::remove_human(r"_cnt$");
+        inflection::remove_human(r"^legacy_col_person_name$");
+        assert_eq!(inflection::humanize("jobs_cnt"), "Jobs cnt");
+        assert_eq!(inflection::humanize("legacy_col_person_name"), "Legacy col person name");
+    }
+
+    #[test]
+    fn titleize_with_bulk() {
+        let keep_id_suffix = inflection::HumanizeOptions {
+            keep_id_suffix: true,
+            ..Default::default()
+        };
+        assert_eq!(inflection::titleize_with("author_id", &keep_id_suffix), "Author Id");
+        assert_eq!(
+            inflection::titleize_with("author_id", &inflection::HumanizeOptions::default()),
+            "Author"
+        );
+        let without_capitalize = inflection::HumanizeOptions {
+            capitalize: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            inflection::titleize_with("active_record", &without_capitalize),
+            "Active Record"
+        );
+    }
+
+    #[test]
+    fn classify_bulk() {
+        for (expected, input) in CLASS_NAME_TO_TABLE_NAME {
+            assert_eq!(inflection::classify(input), expected);
+            assert_eq!(
+                inflection::classify(format!("table_prefix.{}", input)),
+                expected
+            );
+        }
+        assert_eq!(inflection::classify("schema.foo_bar"), "FooBar");
+        assert_eq!(inflection::classify("blog_posts"), "BlogPost");
+        assert_eq!(inflection::classify("schema.posts"), "Post");
+    }
+
+    #[test]
+    fn foreign_key_bulk() {
+        for (input, expected) in CLASS_NAME_TO_FOREIGN_KEY_WITH_UNDERSCORE {
+            assert_eq!(inflection::foreign_key(input), expected);
+        }
+        for (input, expected) in CLASS_NAME_TO_FOREIGN_KEY_WITHOUT_UNDERSCORE {
+            assert_eq!(
+                inflection::foreign_key_with_sep(input, "".to_string()),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn model_name() {
+        let model_name = inflection::ModelName::new("Post::TrackBack");
+        assert_eq!(model_name.name, "Post::TrackBack");
+        assert_eq!(model_name.singular, "post_track_back");
+        assert_eq!(model_name.plural, "post_track_backs");
+        assert_eq!(model_name.element, "track_back");
+        assert_eq!(model_name.collection, "post/track_backs");
+        assert_eq!(model_name.human, "Track back");
+        assert_eq!(model_name.route_key, "post_track_backs");
+        assert_eq!(model_name.singular_route_key, "post_track_back");
+        assert_eq!(model_name.param_key, "post_track_back");
+        assert_eq!(model_name.i18n_key, "post/track_back");
+        assert_eq!(model_name.table_name, "track_backs");
+    }
+
+    #[test]
+    fn model_name_with_namespace() {
+        let model_name = inflection::ModelName::with_namespace("Blog::Post", "Blog");
+        assert_eq!(model_name.singular, "blog_post");
+        assert_eq!(model_name.plural, "blog_posts");
+        assert_eq!(model_name.element, "post");
+        assert_eq!(model_name.collection, "blog/posts");
+        assert_eq!(model_name.human, "Post");
+        assert_eq!(model_name.route_key, "posts");
+        assert_eq!(model_name.singular_route_key, "post");
+        assert_eq!(model_name.param_key, "post");
+        assert_eq!(model_name.i18n_key, "blog/post");
+        assert_eq!(model_name.table_name, "posts");
+    }
+
+    #[test]
+    fn model_name_uncountable() {
+        let model_name = inflection::ModelName::new("Sheep");
+        assert_eq!(model_name.singular, "sheep");
+        assert_eq!(model_name.plural, "sheep");
+        assert_eq!(model_name.route_key, "sheep_index");
+        assert_eq!(model_name.singular_route_key, "sheep");
+    }
+
     #[test]
     fn titleize_bulk() {
+        let _registry = registry_read();
         for (input, expected) in MIXTURE_TO_TITLEIZED {
             assert_eq!(inflection::titleize(input), expected);
         }
     }
 
+    #[test]
+    fn titleize_with_style_bulk() {
+        let _registry = registry_read();
+        for (input, expected) in STRING_TO_CHICAGO_TITLE {
+            assert_eq!(
+                inflection::titleize_with_style(input, inflection::TitleStyle::Chicago),
+                expected
+            );
+        }
+        for (input, expected) in STRING_TO_AP_TITLE {
+            assert_eq!(
+                inflection::titleize_with_style(input, inflection::TitleStyle::Ap),
+                expected
+            );
+            assert_eq!(
+                inflection::titleize_with_style(input, inflection::TitleStyle::Apa),
+                expected
+            );
+        }
+        for (input, expected) in STRING_TO_MLA_TITLE {
+            assert_eq!(
+                inflection::titleize_with_style(input, inflection::TitleStyle::Mla),
+                expected
+            );
+        }
+        assert_eq!(
+            inflection::titleize_with_style("", inflection::TitleStyle::Chicago),
+            ""
+        );
+    }
+
+    #[test]
+    fn preserved_casing() {
+        let _registry = registry_write();
+        inflection::add_preserved_casing("iPhone");
+        inflection::add_preserved_casing("GitHub");
+
+        assert_eq!(inflection::capitalize("iphone"), "iPhone");
+        assert_eq!(inflection::titleize("iphone and github"), "iPhone And GitHub");
+        assert_eq!(inflection::titleize("GitHub repo"), "GitHub Repo");
+        assert_eq!(inflection::titleize("github's issues"), "GitHub's Issues");
+        assert_eq!(inflection::humanize("github_repo"), "GitHub repo");
+        assert_eq!(inflection::humanize("new_iphone_case"), "New iPhone case");
+        assert_eq!(inflection::humanize("iphone_case"), "iPhone case");
+        assert_eq!(
+            inflection::titleize_with_style("the github guide: iphone edition", inflection::TitleStyle::Chicago),
+            "The GitHub Guide: iPhone Edition"
+        );
+        assert_eq!(inflection::keyify("GitHub Repo"), "git_hub_repo");
+
+        inflection::remove_preserved_casing("iphone");
+        assert_eq!(inflection::capitalize("iphone"), "Iphone");
+        inflection::remove_preserved_casing("GitHub");
+        assert_eq!(inflection::humanize("github_repo"), "Github repo");
+    }
+
+    #[test]
+    fn name_prefix_casing() {
+        let _registry = registry_write();
+        assert_eq!(inflection::titleize("o'neill at five o'clock"), "O'Neill At Five O'clock");
+        assert_eq!(inflection::humanize("mcast_group"), "Mcast group");
+        assert_eq!(inflection::titleize("mcast_group"), "Mcast Group");
+        assert_eq!(inflection::capitalize("macintyre"), "Macintyre");
+
+        inflection::set_name_prefix_casing(inflection::NamePrefix::Mc, true);
+        assert_eq!(inflection::titleize("ronald mcdonald"), "Ronald McDonald");
+        assert_eq!(inflection::titleize("Ronald McDonald"), "Ronald McDonald");
+        assert_eq!(inflection::humanize("mcdonald_farm"), "McDonald farm");
+        inflection::set_name_prefix_casing(inflection::NamePrefix::Mc, false);
+        assert_eq!(inflection::humanize("mcdonald_farm"), "Mcdonald farm");
+
+        inflection::set_name_prefix_casing(inflection::NamePrefix::Mac, true);
+        assert_eq!(inflection::capitalize("macintyre"), "MacIntyre");
+        inflection::set_name_prefix_casing(inflection::NamePrefix::Mac, false);
+        assert_eq!(inflection::capitalize("macintyre"), "Macintyre");
+    }
+
+    #[test]
+    fn sentence_case_bulk() {
+        let _registry = registry_read();
+        for (input, expected) in STRING_TO_SENTENCE_CASE {
+            assert_eq!(inflection::sentence_case(input), expected);
+        }
+    }
+
+    #[test]
+    fn sentence_case_with_preserved_casing() {
+        let _registry = registry_write();
+        inflection::add_preserved_casing("FBI");
+        inflection::add_preserved_casing("Paris");
+        assert_eq!(
+            inflection::sentence_case("THE FBI OFFICE IN PARIS"),
+            "The FBI office in Paris"
+        );
+        inflection::remove_preserved_casing("FBI");
+        inflection::remove_preserved_casing("Paris");
+        assert_eq!(
+            inflection::sentence_case("THE FBI OFFICE IN PARIS"),
+            "The fbi office in paris"
+        );
+    }
+
+    #[test]
+    fn subvert_bulk() {
+        for (input, expected) in FACILITY_TO_BUILDING {
+            assert_eq!(inflection::subvert(input, "facility", "building"), expected);
+            assert_eq!(inflection::subvert(input, "facilities", "buildings"), expected);
+        }
+        assert_eq!(
+            inflection::subvert(
+                "UserAccount user_accounts USER_ACCOUNT userAccounts user-account User accounts",
+                "user_account",
+                "CustomerProfile"
+            ),
+            "CustomerProfile customer_profiles CUSTOMER_PROFILE customerProfiles customer-profile Customer profiles"
+        );
+        assert_eq!(
+            inflection::subvert("child children Children CHILD_ID", "child", "person"),
+            "person people People PERSON_ID"
+        );
+        assert_eq!(inflection::subvert("nothing to see", "", "building"), "nothing to see");
+    }
+
+    #[test]
+    fn identifier_matcher() {
+        use inflection::{CaseStyle, GrammaticalNumber};
+
+        let matcher = inflection::IdentifierMatcher::new("user_account");
+        let text = "user_account, UserAccount; userAccounts USER-ACCOUNTS superuser_account";
+        let found: Vec<(usize, usize, &str, CaseStyle, GrammaticalNumber)> = matcher
+            .find_all(text)
+            .iter()
+            .map(|m| (m.start, m.end, &text[m.start..m.end], m.style, m.number))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (0, 12, "user_account", CaseStyle::Snake, GrammaticalNumber::Singular),
+                (14, 25, "UserAccount", CaseStyle::Camel, GrammaticalNumber::Singular),
+                (27, 39, "userAccounts", CaseStyle::LowerCamel, GrammaticalNumber::Plural),
+                (40, 53, "USER-ACCOUNTS", CaseStyle::ScreamingKebab, GrammaticalNumber::Plural),
+            ]
+        );
+        assert!(matcher.is_match("let accounts = load_user_accounts();"));
+        assert!(!matcher.is_match("superuser_accountant"));
+        assert!(matcher.as_regex().is_match("UserAccount"));
+
+        let matcher = inflection::IdentifierMatcher::new("People");
+        let found = matcher.find_all("person_id people Person");
+        assert_eq!(found.len(), 3);
+        assert_eq!(found[0].number, GrammaticalNumber::Singular);
+        assert_eq!(found[1].number, GrammaticalNumber::Plural);
+        assert_eq!(found[2].style, CaseStyle::Camel);
+
+        assert!(inflection::IdentifierMatcher::new("").find_all("anything").is_empty());
+    }
+
+    #[test]
+    fn canonical_key_bulk() {
+        for (input, expected) in STRING_TO_CANONICAL_KEY {
+            assert_eq!(inflection::canonical_key(input), expected);
+        }
+    }
+
+    #[test]
+    fn same_identifier() {
+        assert!(inflection::same_identifier("Categories", "category"));
+        assert!(inflection::same_identifier("CATEGORY", "categories"));
+        assert!(inflection::same_identifier("userAccounts", "USER-ACCOUNT"));
+        assert!(!inflection::same_identifier("user_account", "user"));
+        assert!(!inflection::same_identifier("category", "categorization"));
+    }
+
+    #[test]
+    fn sanitize_identifier_keywords() {
+        use inflection::{IdentifierKind, Language};
+
+        let cases = [
+            (Language::Rust, "type", "r#type"),
+            (Language::Rust, "self", "self_"),
+            (Language::Python, "class", "class_"),
+            (Language::Go, "type", "type_"),
+            (Language::TypeScript, "class", "class_"),
+            (Language::Java, "default", "default_"),
+            (Language::Sql, "user", "\"user\""),
+            (Language::Sql, "Order", "\"order\""),
+        ];
+        for (language, input, expected) in cases {
+            assert_eq!(
+                inflection::sanitize_identifier(input, language, IdentifierKind::Variable),
+                expected
+            );
+        }
+        assert_eq!(
+            inflection::sanitize_identifier("self", Language::Rust, IdentifierKind::Type),
+            "Self_"
+        );
+        assert_eq!(
+            inflection::sanitize_identifier("none", Language::Python, IdentifierKind::Type),
+            "None_"
+        );
+    }
+
+    #[test]
+    fn sanitize_identifier_conventions() {
+        use inflection::{IdentifierKind, Language};
+
+        let cases = [
+            (Language::Rust, IdentifierKind::Variable, "userAccount", "user_account"),
+            (Language::Rust, IdentifierKind::Type, "user account", "UserAccount"),
+            (Language::Rust, IdentifierKind::Constant, "max-retries", "MAX_RETRIES"),
+            (Language::Python, IdentifierKind::Variable, "UserAccount", "user_account"),
+            (Language::Go, IdentifierKind::Variable, "user_id", "userID"),
+            (Language::Go, IdentifierKind::Type, "http_server_url", "HTTPServerURL"),
+            (Language::Go, IdentifierKind::Variable, "http_server_url", "httpServerURL"),
+            (Language::Go, IdentifierKind::Constant, "api_key", "APIKey"),
+            (Language::TypeScript, IdentifierKind::Variable, "first name", "firstName"),
+            (Language::TypeScript, IdentifierKind::Constant, "firstName", "FIRST_NAME"),
+            (Language::Java, IdentifierKind::Type, "order_item", "OrderItem"),
+            (Language::Sql, IdentifierKind::Type, "OrderItems", "order_items"),
+            (Language::Rust, IdentifierKind::Variable, "1st place", "_1st_place"),
+            (Language::Java, IdentifierKind::Type, "3d-model", "_3dModel"),
+            (Language::Python, IdentifierKind::Variable, "", "unnamed"),
+            (Language::Rust, IdentifierKind::Type, "!!!", "Unnamed"),
+            (Language::TypeScript, IdentifierKind::Variable, "café crème", "cafeCreme"),
+        ];
+        for (language, kind, input, expected) in cases {
+            assert_eq!(inflection::sanitize_identifier(input, language, kind), expected);
+        }
+    }
+
+    #[test]
+    fn index_name() {
+        use inflection::SqlDialect;
+
+        assert_eq!(
+            inflection::index_name("posts", &["user_id"], SqlDialect::Postgres),
+            "index_posts_on_user_id"
+        );
+        assert_eq!(
+            inflection::index_name("posts", &["user_id", "created_at"], SqlDialect::MySql),
+            "index_posts_on_user_id_and_created_at"
+        );
+
+        let columns = ["post_id", "author_id", "created_at", "updated_at", "state"];
+        let name = inflection::index_name("comments", &columns, SqlDialect::Postgres);
+        assert_eq!(name, "idx_on_post_id_author_id_created_at_updated_at_state_f03d907bad");
+        assert_eq!(name.len(), 63);
+        assert_eq!(
+            inflection::index_name("comments", &columns, SqlDialect::Sqlite),
+            "index_comments_on_post_id_and_author_id_and_created_at_and_updated_at_and_state"
+        );
+    }
+
+    #[test]
+    fn constraint_names() {
+        assert_eq!(inflection::foreign_key_name("posts", &["user_id"]), "fk_rails_5b5ddfd518");
+        assert_eq!(
+            inflection::check_constraint_name("posts", "price > 0"),
+            "chk_rails_f731d39383"
+        );
+    }
+
+    #[test]
+    fn shorten_identifier() {
+        use inflection::SqlDialect;
+
+        let name = "this_is_an_extremely_long_table_name_that_goes_past_the_postgres_limit";
+        let shortened = inflection::shorten_identifier(name, SqlDialect::Postgres);
+        assert_eq!(shortened.len(), 63);
+        assert!(shortened.ends_with("_07fe0021ab"));
+        assert_eq!(shortened, inflection::shorten_identifier(name, SqlDialect::Postgres));
+        assert_eq!(inflection::shorten_identifier(name, SqlDialect::Oracle), name);
+        assert_eq!(inflection::shorten_identifier("posts", SqlDialect::Postgres), "posts");
+
+        let multibyte = "ü".repeat(40);
+        let shortened = inflection::shorten_identifier(&multibyte, SqlDialect::MySql);
+        assert!(shortened.len() <= 64);
+    }
+
+    #[test]
+    fn join_table_name() {
+        use inflection::SqlDialect;
+
+        assert_eq!(inflection::join_table_name("Tag", "Post", SqlDialect::Postgres), "posts_tags");
+        assert_eq!(
+            inflection::join_table_name("CatalogProduct", "CatalogCategory", SqlDialect::Postgres),
+            "catalog_categories_products"
+        );
+        assert_eq!(
+            inflection::join_table_name("Admin::User", "Group", SqlDialect::MySql),
+            "groups_users"
+        );
+    }
+
+    #[test]
+    fn join_table_name_with() {
+        use inflection::{AssociationOptions, SqlDialect};
+
+        let options = AssociationOptions {
+            prefix: "app_".to_string(),
+            ..AssociationOptions::default()
+        };
+        assert_eq!(inflection::join_table_name_with("Tag", "Post", &options), "app_posts_tags");
+
+        let options = AssociationOptions {
+            keep_namespace: true,
+            ..AssociationOptions::default()
+        };
+        assert_eq!(
+            inflection::join_table_name_with("Admin::User", "Admin::Role", &options),
+            "admin_roles_users"
+        );
+        assert_eq!(
+            inflection::join_table_name_with("Admin::User", "Group", &options),
+            "admin_users_groups"
+        );
+
+        let options = AssociationOptions {
+            prefix: "x".repeat(60),
+            dialect: Some(SqlDialect::Postgres),
+            ..AssociationOptions::default()
+        };
+        assert_eq!(inflection::join_table_name_with("Tag", "Post", &options).len(), 63);
+    }
+
+    #[test]
+    fn association_names() {
+        use inflection::{AssociationKind, AssociationOptions};
+
+        assert_eq!(inflection::association_name("Blog::Comment", AssociationKind::HasMany), "comments");
+        assert_eq!(inflection::association_name("Person", AssociationKind::HasAndBelongsToMany), "people");
+        assert_eq!(inflection::association_name("Blog::Author", AssociationKind::BelongsTo), "author");
+        assert_eq!(inflection::through_association_name("Post", "Tag"), "post_tags");
+        assert_eq!(inflection::inverse_association_name("Post", AssociationKind::HasMany), "post");
+        assert_eq!(inflection::inverse_association_name("Comment", AssociationKind::BelongsTo), "comments");
+        assert_eq!(
+            inflection::inverse_association_name("Person", AssociationKind::HasAndBelongsToMany),
+            "people"
+        );
+
+        let options = AssociationOptions {
+            prefix: "owned_".to_string(),
+            keep_namespace: true,
+            ..AssociationOptions::default()
+        };
+        assert_eq!(
+            inflection::association_name_with("Blog::Comment", AssociationKind::HasMany, &options),
+            "owned_blog_comments"
+        );
+        assert_eq!(
+            inflection::through_association_name_with("Blog::Post", "Tag", &options),
+            "owned_blog_post_tags"
+        );
+        assert_eq!(
+            inflection::inverse_association_name_with("Blog::Post", AssociationKind::HasOne, &options),
+            "owned_blog_post"
+        );
+    }
+
+    const PLURALIZE_COUNT_INTEGERS: [(i64, &str, &str); 6] = [
+        (1, "item", "1 item"),
+        (3, "item", "3 items"),
+        (0, "item", "no items"),
+        (-1, "degree", "-1 degree"),
+        (2, "person", "2 people"),
+        (1, "people", "1 person"),
+    ];
+
+    const PLURALIZE_COUNT_FLOATS: [(f64, &str, &str); 4] = [
+        (1.5, "mile", "1.5 miles"),
+        (1.0, "mile", "1 mile"),
+        (0.5, "cup", "0.5 cups"),
+        (0.0, "cup", "no cups"),
+    ];
+
+    #[test]
+    fn pluralize_count_bulk() {
+        for (count, word, expected) in PLURALIZE_COUNT_INTEGERS {
+            assert_eq!(inflection::pluralize_count(count, word), expected);
+        }
+        for (count, word, expected) in PLURALIZE_COUNT_FLOATS {
+            assert_eq!(inflection::pluralize_count(count, word), expected);
+        }
+        assert_eq!(inflection::pluralize_count(4usize, "sheep"), "4 sheep");
+    }
+
+    #[test]
+    fn pluralize_count_with_options() {
+        use inflection::CountOptions;
+
+        let options = CountOptions {
+            zero: None,
+            thousands_separator: Some(','),
+            precision: Some(1),
+            ..CountOptions::default()
+        };
+        assert_eq!(inflection::pluralize_count_with(0, "item", &options), "0 items");
+        assert_eq!(inflection::pluralize_count_with(1234567, "item", &options), "1,234,567 items");
+        assert_eq!(inflection::pluralize_count_with(-1234.56, "mile", &options), "-1,234.6 miles");
+        assert_eq!(inflection::pluralize_count_with(1.0, "mile", &options), "1.0 miles");
+        assert_eq!(inflection::pluralize_count_with(100, "item", &options), "100 items");
+
+        let options = CountOptions {
+            spell_out: true,
+            ..CountOptions::default()
+        };
+        assert_eq!(inflection::pluralize_count_with(1, "item", &options), "one item");
+        assert_eq!(inflection::pluralize_count_with(21, "box", &options), "twenty-one boxes");
+        assert_eq!(inflection::pluralize_count_with(0, "box", &options), "no boxes");
+        assert_eq!(inflection::pluralize_count_with(2.5, "hour", &options), "2.5 hours");
+
+        let options = CountOptions {
+            zero: Some("zero".to_string()),
+            ..CountOptions::default()
+        };
+        assert_eq!(inflection::pluralize_count_with(0, "result", &options), "zero results");
+    }
+
+    const NUMBER_WORDS: [(i128, &str); 9] = [
+        (0, "zero"),
+        (7, "seven"),
+        (15, "fifteen"),
+        (40, "forty"),
+        (99, "ninety-nine"),
+        (100, "one hundred"),
+        (1001, "one thousand one"),
+        (-342, "minus three hundred forty-two"),
+        (2_000_010_500, "two billion ten thousand five hundred"),
+    ];
+
+    #[test]
+    fn number_to_words_bulk() {
+        for (number, expected) in NUMBER_WORDS {
+            assert_eq!(inflection::number_to_words(number), expected);
+        }
+        assert!(inflection::number_to_words(i128::MIN).starts_with("minus one hundred seventy undecillion"));
+    }
+
+    const INFLECT_TEMPLATES: [(&str, &str); 18] = [
+        ("There PL_V(was,3) NO(error,3)", "There were 3 errors"),
+        ("There PL_V(was,1) NO(error,1)", "There was 1 error"),
+        ("There PL_V(was,0) NO(error,0)", "There were no errors"),
+        ("PL_ADJ(This,2) PL_N(person,2) PL_V(is,2) here", "These people are here"),
+        ("PL_ADJ(This,1) PL_N(person,1) PL_V(is,1) here", "This person is here"),
+        ("NUM(2) PL(child) PL_V(plays) with PL_ADJ(its) PL(toy)", "2 children play with their toys"),
+        ("NUM(1) PL(child) PL_V(plays) with PL_ADJ(its) PL(toy)", "1 child plays with its toy"),
+        ("The PL(mouse)", "The mice"),
+        ("A(user) and AN(hour)", "a user and an hour"),
+        ("NUMWORDS(42) results", "forty-two results"),
+        ("the ORD(3) attempt", "the 3rd attempt"),
+        ("Walked NO(mile,2.5)", "Walked 2.5 miles"),
+        ("It PL_V(watches,2) and PL_V(tries,2)", "It watch and try"),
+        ("Unknown FOO(bar) stays", "Unknown FOO(bar) stays"),
+        ("NO(error,1e40)", "10000000000000000000000000000000000000000 errors"),
+        ("NO(error,many)", "NO(error,many)"),
+        ("A() PL() NO(,2) PL(cat,)", "A() PL() NO(,2) PL(cat,)"),
+        ("PL(cat,2,3)", "PL(cat,2,3)"),
+    ];
+
+    #[test]
+    fn inflect_bulk() {
+        for (template, expected) in INFLECT_TEMPLATES {
+            assert_eq!(inflection::inflect(template), expected);
+        }
+        let n = 5;
+        assert_eq!(
+            inflection::inflect(format!("PL_V(Has,{n}) NO(file,{n}) changed")),
+            "Have 5 files changed"
+        );
+    }
+
+    const VERBS: [(&str, &str); 23] = [
+        ("is", "are"),
+        ("was", "were"),
+        ("has", "have"),
+        ("does", "do"),
+        ("doesn't", "don't"),
+        ("runs", "run"),
+        ("goes", "go"),
+        ("watches", "watch"),
+        ("fixes", "fix"),
+        ("misses", "miss"),
+        ("tries", "try"),
+        ("plays", "play"),
+        ("uses", "use"),
+        ("optimizes", "optimize"),
+        ("sizes", "size"),
+        ("freezes", "freeze"),
+        ("toes", "toe"),
+        ("echoes", "echo"),
+        ("focuses", "focus"),
+        ("quizzes", "quiz"),
+        ("gases", "gas"),
+        ("buses", "bus"),
+        ("Is", "Are"),
+    ];
+
+    #[test]
+    fn pluralize_verb_bulk() {
+        let _registry = registry_read();
+        for (singular, plural) in VERBS {
+            assert_eq!(inflection::pluralize_verb(singular), plural);
+            assert_eq!(inflection::pluralize_verb(plural), plural);
+        }
+        assert_eq!(inflection::pluralize_verb("FIXES"), "FIX");
+        assert_eq!(inflection::pluralize_verb("ȺXES"), "ȺX");
+    }
+
+    #[test]
+    fn singularize_verb_bulk() {
+        let _registry = registry_read();
+        for (singular, plural) in VERBS {
+            assert_eq!(inflection::singularize_verb(plural), singular);
+            assert_eq!(inflection::singularize_verb(singular), singular);
+        }
+        assert_eq!(inflection::singularize_verb("HAVE"), "HAS");
+        assert_eq!(inflection::singularize_verb("focus"), "focuses");
+        assert_eq!(inflection::singularize_verb("Carry"), "Carries");
+        assert_eq!(inflection::singularize_verb("PUSH"), "PUSHES");
+    }
+
+    #[test]
+    fn modal_verbs() {
+        for modal in ["can", "must", "should", "will", "Might"] {
+            assert_eq!(inflection::pluralize_verb(modal), modal);
+            assert_eq!(inflection::singularize_verb(modal), modal);
+        }
+    }
+
+    #[test]
+    fn irregular_verb_registration() {
+        let _registry = registry_write();
+        assert_eq!(inflection::singularize_verb("shan't"), "shan'ts");
+        inflection::add_irregular_verb("shan't", "shan't");
+        assert_eq!(inflection::singularize_verb("shan't"), "shan't");
+        inflection::remove_irregular_verb("shan't");
+        assert_eq!(inflection::singularize_verb("shan't"), "shan'ts");
+
+        inflection::add_irregular_verb("blorfs", "blorfen");
+        assert_eq!(inflection::pluralize_verb("Blorfs"), "Blorfen");
+        assert_eq!(inflection::singularize_verb("blorfen"), "blorfs");
+        inflection::remove_irregular_verb("Blorfs");
+        assert_eq!(inflection::singularize_verb("blorfen"), "blorfens");
+    }
+
+    const CONJUGATIONS: [(&str, &str, &str, &str, &str); 25] = [
+        ("delete", "deleted", "deleted", "deleting", "deletes"),
+        ("create", "created", "created", "creating", "creates"),
+        ("stop", "stopped", "stopped", "stopping", "stops"),
+        ("plan", "planned", "planned", "planning", "plans"),
+        ("open", "opened", "opened", "opening", "opens"),
+        ("visit", "visited", "visited", "visiting", "visits"),
+        ("commit", "committed", "committed", "committing", "commits"),
+        ("prefer", "preferred", "preferred", "preferring", "prefers"),
+        ("try", "tried", "tried", "trying", "tries"),
+        ("play", "played", "played", "playing", "plays"),
+        ("fix", "fixed", "fixed", "fixing", "fixes"),
+        ("push", "pushed", "pushed", "pushing", "pushes"),
+        ("panic", "panicked", "panicked", "panicking", "panics"),
+        ("die", "died", "died", "dying", "dies"),
+        ("see", "saw", "seen", "seeing", "sees"),
+        ("agree", "agreed", "agreed", "agreeing", "agrees"),
+        ("be", "was", "been", "being", "is"),
+        ("have", "had", "had", "having", "has"),
+        ("go", "went", "gone", "going", "goes"),
+        ("write", "wrote", "written", "writing", "writes"),
+        ("run", "ran", "run", "running", "runs"),
+        ("quiz", "quizzed", "quizzed", "quizzing", "quizzes"),
+        ("squat", "squatted", "squatted", "squatting", "squats"),
+        ("equip", "equipped", "equipped", "equipping", "equips"),
+        ("Delete", "Deleted", "Deleted", "Deleting", "Deletes"),
+    ];
+
+    #[test]
+    fn conjugation_bulk() {
+        let _registry = registry_read();
+        for (base, past, participle, present, third) in CONJUGATIONS {
+            assert_eq!(inflection::past_tense(base), past);
+            assert_eq!(inflection::past_participle(base), participle);
+            assert_eq!(inflection::present_participle(base), present);
+            assert_eq!(inflection::third_person(base), third);
+        }
+        assert_eq!(inflection::past_tense("UPLOAD"), "UPLOADED");
+        assert_eq!(inflection::present_participle(""), "");
+    }
+
+    #[test]
+    fn irregular_conjugation_registration() {
+        let _registry = registry_write();
+        assert_eq!(inflection::past_tense("yeet"), "yeeted");
+        inflection::add_irregular_conjugation("yeet", "yote", "yoten");
+        assert_eq!(inflection::past_tense("Yeet"), "Yote");
+        assert_eq!(inflection::past_participle("yeet"), "yoten");
+        assert_eq!(inflection::present_participle("yeet"), "yeeting");
+        inflection::remove_irregular_conjugation("yeet");
+        assert_eq!(inflection::past_participle("yeet"), "yeeted");
+    }
+
+    const INDEFINITE_ARTICLES: [(&str, &str); 36] = [
+        ("user", "a user"),
+        ("apple", "an apple"),
+        ("hour", "an hour"),
+        ("honest mistake", "an honest mistake"),
+        ("house", "a house"),
+        ("URL", "a URL"),
+        ("FAQ", "an FAQ"),
+        ("HTML page", "an HTML page"),
+        ("NASA mission", "a NASA mission"),
+        ("HOUSE", "a HOUSE"),
+        ("OPEN ticket", "an OPEN ticket"),
+        ("SQL query", "an SQL query"),
+        ("one-time code", "a one-time code"),
+        ("once-off fee", "a once-off fee"),
+        ("onion", "an onion"),
+        ("European", "a European"),
+        ("unicorn", "a unicorn"),
+        ("uninstall", "an uninstall"),
+        ("unidentified", "an unidentified"),
+        ("uninformed", "an uninformed"),
+        ("unidirectional", "a unidirectional"),
+        ("NYC", "an NYC"),
+        ("Herbert", "a Herbert"),
+        ("oneself", "a oneself"),
+        ("onerous", "an onerous"),
+        ("umbrella", "an umbrella"),
+        ("utility", "a utility"),
+        ("8", "an 8"),
+        ("80", "an 80"),
+        ("11", "an 11"),
+        ("18,000", "an 18,000"),
+        ("110", "a 110"),
+        ("1", "a 1"),
+        ("x-ray", "an x-ray"),
+        ("u-turn", "a u-turn"),
+        ("Item", "an Item"),
+    ];
+
+    #[test]
+    fn indefinite_article_bulk() {
+        let _registry = registry_read();
+        for (word, expected) in INDEFINITE_ARTICLES {
+            assert_eq!(inflection::with_article(word), expected);
+        }
+        assert_eq!(inflection::indefinite_article("hour"), "an");
+    }
+
+    #[test]
+    fn indefinite_article_overrides() {
+        let _registry = registry_write();
+        assert_eq!(inflection::indefinite_article("LHC"), "an");
+        inflection::add_indefinite_article("LHC", "a");
+        assert_eq!(inflection::with_article("LHC run"), "a LHC run");
+        inflection::remove_indefinite_article("lhc");
+        assert_eq!(inflection::indefinite_article("LHC"), "an");
+    }
+
+    const POSSESSIVES: [(&str, &str, &str); 16] = [
+        ("user", "user's", "users'"),
+        ("child", "child's", "children's"),
+        ("person", "person's", "people's"),
+        ("boss", "boss's", "bosses'"),
+        ("status", "status's", "statuses'"),
+        ("sheep", "sheep's", "sheep's"),
+        ("users", "users'", "users'"),
+        ("children", "children's", "children's"),
+        ("James", "James's", "James'"),
+        ("Thomas", "Thomas's", "Thomas'"),
+        ("Users", "Users'", "Users'"),
+        ("Employees", "Employees'", "Employees'"),
+        ("Cats", "Cats'", "Cats'"),
+        ("Statuses", "Statuses'", "Statuses'"),
+        ("BOX", "BOX's", "BOXES'"),
+        ("FBI", "FBI's", "FBIs'"),
+    ];
+
+    #[test]
+    fn possessive_bulk() {
+        let _registry = registry_read();
+        for (noun, singular, plural) in POSSESSIVES {
+            assert_eq!(inflection::possessive(noun), singular);
+            assert_eq!(inflection::plural_possessive(noun), plural);
+        }
+        assert_eq!(inflection::possessive("user's"), "user's");
+        assert_eq!(inflection::possessive(""), "");
+    }
+
+    #[test]
+    fn possessive_with_style() {
+        let _registry = registry_read();
+        use inflection::PossessiveStyle;
+
+        assert_eq!(inflection::possessive_with_style("James", PossessiveStyle::Chicago), "James's");
+        assert_eq!(inflection::possessive_with_style("James", PossessiveStyle::Ap), "James'");
+        assert_eq!(inflection::possessive_with_style("boss", PossessiveStyle::Ap), "boss's");
+        assert_eq!(inflection::possessive_with_style("Paris", PossessiveStyle::Ap), "Paris'");
+        assert_eq!(inflection::possessive_with_style("Bob", PossessiveStyle::Ap), "Bob's");
+    }
+
+    const ADJECTIVES: [(&str, &str, &str); 20] = [
+        ("fast", "faster", "fastest"),
+        ("tall", "taller", "tallest"),
+        ("large", "larger", "largest"),
+        ("free", "freer", "freest"),
+        ("big", "bigger", "biggest"),
+        ("hot", "hotter", "hottest"),
+        ("new", "newer", "newest"),
+        ("cool", "cooler", "coolest"),
+        ("happy", "happier", "happiest"),
+        ("dry", "drier", "driest"),
+        ("simple", "simpler", "simplest"),
+        ("narrow", "narrower", "narrowest"),
+        ("reliable", "more reliable", "most reliable"),
+        ("careful", "more careful", "most careful"),
+        ("modern", "more modern", "most modern"),
+        ("bored", "more bored", "most bored"),
+        ("good", "better", "best"),
+        ("bad", "worse", "worst"),
+        ("Good", "Better", "Best"),
+        ("Reliable", "More reliable", "Most reliable"),
+    ];
+
+    #[test]
+    fn comparison_bulk() {
+        let _registry = registry_read();
+        for (base, comparative, superlative) in ADJECTIVES {
+            assert_eq!(inflection::comparative(base), comparative);
+            assert_eq!(inflection::superlative(base), superlative);
+        }
+        assert_eq!(inflection::comparative(""), "");
+    }
+
+    #[test]
+    fn irregular_adjective_registration() {
+        let _registry = registry_write();
+        assert_eq!(inflection::comparative("snazzy"), "snazzier");
+        inflection::add_irregular_adjective("snazzy", "more snazzy", "most snazzy");
+        assert_eq!(inflection::superlative("snazzy"), "most snazzy");
+        inflection::remove_irregular_adjective("snazzy");
+        assert_eq!(inflection::superlative("snazzy"), "snazziest");
+    }
+
+    const COMPOUND_NOUNS: [(&str, &str); 12] = [
+        ("runner-up", "runners-up"),
+        ("hanger-on", "hangers-on"),
+        ("passer-by", "passers-by"),
+        ("court martial", "courts martial"),
+        ("court-martial", "courts-martial"),
+        ("editor-in-chief", "editors-in-chief"),
+        ("editor in chief", "editors in chief"),
+        ("attorney general", "attorneys general"),
+        ("president-elect", "presidents-elect"),
+        ("man-of-war", "men-of-war"),
+        ("mother-in-law", "mothers-in-law"),
+        ("notary public", "notaries public"),
+    ];
+
+    const OPEN_COMPOUNDS: [(&str, &str); 6] = [
+        ("sign-up", "sign-ups"),
+        ("major general", "major generals"),
+        ("grown-up", "grown-ups"),
+        ("check-in", "check-ins"),
+        ("cover-up", "cover-ups"),
+        ("power-up", "power-ups"),
+    ];
+
+    #[test]
+    fn compound_noun_bulk() {
+        let _registry = registry_read();
+        for (singular, plural) in COMPOUND_NOUNS {
+            assert_eq!(inflection::pluralize(singular), plural);
+            assert_eq!(inflection::singularize(plural), singular);
+        }
+        for (singular, plural) in OPEN_COMPOUNDS {
+            assert_eq!(inflection::pluralize(singular), plural);
+        }
+    }
+
+    #[test]
+    fn postpositive_registration() {
+        let _registry = registry_write();
+        assert_eq!(inflection::pluralize("knight errant"), "knight errants");
+        inflection::add_postpositive(r"^(?P<head>.+?)(?P<tail> errant)$").unwrap();
+        assert_eq!(inflection::pluralize("knight errant"), "knights errant");
+        assert_eq!(inflection::singularize("knights errant"), "knight errant");
+        assert!(inflection::add_postpositive(r"^.+ errant$").is_err());
+        assert!(inflection::add_postpositive(r"(?P<head>").is_err());
+
+        inflection::remove_postpositive(r"^(?P<head>.+?)(?P<tail> errant)$");
+        assert_eq!(inflection::pluralize("knight errant"), "knight errants");
+
+        inflection::add_postpositive(r"^(?P<head>.+?)(?P<tail> vagrant)$").unwrap();
+        inflection::clear_postpositives();
+        assert_eq!(inflection::pluralize("knight vagrant"), "knight vagrants");
+        assert_eq!(inflection::pluralize("runner-up"), "runners-up");
+    }
+
+    const CASED_PLURALS: [(&str, &str); 26] = [
+        ("PERSON", "PEOPLE"),
+        ("Person", "People"),
+        ("BOX", "BOXES"),
+        ("Box", "Boxes"),
+        ("SHEEP", "SHEEP"),
+        ("Sheep", "Sheep"),
+        ("CHILD", "CHILDREN"),
+        ("CITY", "CITIES"),
+        ("OCTOPUS", "OCTOPI"),
+        ("COW", "KINE"),
+        ("QUIZ", "QUIZZES"),
+        ("DATUM", "DATA"),
+        ("MOTHER-IN-LAW", "MOTHERS-IN-LAW"),
+        ("Runner-Up", "Runners-Up"),
+        ("Attorney General", "Attorneys General"),
+        ("CamelOctopus", "CamelOctopi"),
+        ("userPerson", "userPeople"),
+        ("BlogPOST", "BlogPOSTS"),
+        ("FBI", "FBIs"),
+        ("BlogAPI", "BlogAPIs"),
+        ("userID", "userIDs"),
+        ("HostOS", "HostOSes"),
+        ("Area51", "Area51s"),
+        ("Version2", "Version2s"),
+        ("HTML5", "HTML5s"),
+        ("Mp3player", "Mp3players"),
+    ];
+
+    #[test]
+    fn case_preserving_bulk() {
+        let _registry = registry_read();
+        for (singular, plural) in CASED_PLURALS {
+            assert_eq!(inflection::pluralize(singular), plural);
+            assert_eq!(inflection::singularize(plural), singular);
+        }
+    }
+
+    const SYMBOL_PLURALS: [(&str, &str); 12] = [
+        ("CPU", "CPUs"),
+        ("FAQ", "FAQs"),
+        ("API", "APIs"),
+        ("URL", "URLs"),
+        ("HTML", "HTMLs"),
+        ("PhD", "PhDs"),
+        ("OS", "OSes"),
+        ("1990", "1990s"),
+        ("'90", "'90s"),
+        ("7", "7s"),
+        ("A", "A's"),
+        ("x", "x's"),
+    ];
+
+    #[test]
+    fn symbol_plurals_bulk() {
+        let _registry = registry_read();
+        for (singular, plural) in SYMBOL_PLURALS {
+            assert_eq!(inflection::pluralize(singular), plural);
+            assert_eq!(inflection::pluralize(plural), plural);
+            assert_eq!(inflection::singularize(plural), singular);
+            assert_eq!(inflection::singularize(singular), singular);
+        }
+        assert_eq!(inflection::pluralize("BOX"), "BOXES");
+        assert_eq!(inflection::singularize("as"), "a");
+        for (singular, plural) in [
+            ("SKY", "SKIES"),
+            ("FLY", "FLIES"),
+            ("LYNX", "LYNXES"),
+            ("MYTH", "MYTHS"),
+            ("GYM", "GYMS"),
+        ] {
+            assert_eq!(inflection::pluralize(singular), plural);
+        }
+        assert_eq!(inflection::pluralize("HTMLS"), "HTMLS");
+    }
+
+    #[test]
+    fn acronym_registration() {
+        let _registry = registry_write();
+        assert_eq!(inflection::pluralize("SKU"), "SKUS");
+        inflection::add_acronym("SKU");
+        assert_eq!(inflection::pluralize("SKU"), "SKUs");
+        assert_eq!(inflection::singularize("SKUs"), "SKU");
+        inflection::remove_acronym("SKU");
+        assert_eq!(inflection::pluralize("SKU"), "SKUS");
+    }
+
     #[test]
     fn keyify_test() {
         for (input, expected) in KEYIFY_BULK {